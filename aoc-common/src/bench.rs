@@ -0,0 +1,171 @@
+//! Repeated timing of parsing and solving, and a history of earlier runs to compare against.
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::input;
+
+/// Where the medians of every benchmark run are appended, relative to the workspace root.
+pub const HISTORY_PATH: &str = "bench-history.tsv";
+
+/// Summary of the durations of repeated runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// The nearest-rank 95th percentile.
+    pub p95: Duration,
+}
+
+impl Stats {
+    /// Panics when `samples` is empty.
+    pub fn new(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        let len = samples.len();
+        let total = samples.iter().sum::<Duration>();
+
+        Stats {
+            min: samples[0],
+            median: samples[len / 2],
+            mean: total / len as u32,
+            p95: samples[(len * 95).div_ceil(100) - 1],
+        }
+    }
+}
+
+/// Timings of one part, parsing and solving measured apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bench {
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// Parses `input` and solves the parsed input `runs` times, at least once, timing both steps.
+/// Stops at the first error.
+pub fn measure<I>(
+    input: &str,
+    runs: usize,
+    parse: impl Fn(&str) -> Result<I>,
+    solve: fn(&I) -> Result<Answer>,
+) -> Result<(Answer, Bench)> {
+    let mut parse_samples = Vec::with_capacity(runs);
+    let mut solve_samples = Vec::with_capacity(runs);
+    let mut answer = None;
+
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let parsed = parse(input)?;
+        parse_samples.push(start.elapsed());
+
+        let start = Instant::now();
+        answer = Some(solve(&parsed)?);
+        solve_samples.push(start.elapsed());
+    }
+
+    let bench = Bench {
+        runs: parse_samples.len(),
+        parse: Stats::new(parse_samples),
+        solve: Stats::new(solve_samples),
+    };
+    Ok((answer.expect("solved at least once"), bench))
+}
+
+/// One line of the history file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// Runs on different inputs are not compared with each other.
+    pub input_sha256: String,
+    pub parse_median: Duration,
+    pub solve_median: Duration,
+}
+
+impl Record {
+    pub fn total(&self) -> Duration {
+        self.parse_median + self.solve_median
+    }
+
+    /// How much slower `self` is than `previous`, in percent. Negative when it got faster.
+    pub fn slowdown_from(&self, previous: &Record) -> f64 {
+        let previous = previous.total().as_secs_f64();
+        if previous == 0.0 {
+            return 0.0;
+        }
+        (self.total().as_secs_f64() - previous) / previous * 100.0
+    }
+}
+
+/// Every record in the history file, oldest first. A missing file is an empty history, lines
+/// that cannot be read are skipped.
+pub fn read_history() -> Result<Vec<Record>> {
+    let path = input::input_path(HISTORY_PATH)?;
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(source) => return Err(Error::Read { path, source }),
+    };
+
+    Ok(text.lines().filter_map(parse_record).collect())
+}
+
+/// The latest record for the same day, part and input.
+pub fn previous<'a>(history: &'a [Record], record: &Record) -> Option<&'a Record> {
+    history.iter().rev().find(|previous| {
+        (previous.day, previous.part, &previous.input_sha256)
+            == (record.day, record.part, &record.input_sha256)
+    })
+}
+
+/// Appends `records` to the history file, each stamped with the current time.
+pub fn append_history(records: &[Record]) -> Result<()> {
+    let path = input::input_path(HISTORY_PATH)?;
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let write = || {
+        let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+        for record in records {
+            writeln!(
+                file,
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                timestamp,
+                record.day,
+                record.part,
+                record.input_sha256,
+                record.parse_median.as_nanos(),
+                record.solve_median.as_nanos()
+            )?;
+        }
+        Ok(())
+    };
+
+    write().map_err(|source| Error::Write { path, source })
+}
+
+/// `timestamp day part input_sha256 parse_median_ns solve_median_ns`, separated by tabs.
+fn parse_record(line: &str) -> Option<Record> {
+    let [
+        _timestamp,
+        day,
+        part,
+        input_sha256,
+        parse_median,
+        solve_median,
+    ] = line.split('\t').collect::<Vec<_>>().try_into().ok()?;
+    let nanos = |raw: &str| raw.parse::<u64>().ok().map(Duration::from_nanos);
+
+    Some(Record {
+        day: day.parse().ok()?,
+        part: part.parse().ok()?,
+        input_sha256: input_sha256.to_string(),
+        parse_median: nanos(parse_median)?,
+        solve_median: nanos(solve_median)?,
+    })
+}
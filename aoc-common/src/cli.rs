@@ -0,0 +1,236 @@
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::input;
+use crate::log::{LOG_USAGE, LogConfig};
+use crate::params::{self, PARAM_USAGE};
+use crate::report::{FORMAT_USAGE, Format, Report};
+use crate::solution::Solution;
+
+/// Where a solution reads its puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's own `input.txt`.
+    Default,
+    /// The matching file under `test-inputs/`.
+    Example,
+    /// A path given on the command line, relative to the working directory.
+    File(PathBuf),
+    Stdin,
+}
+
+pub const INPUT_USAGE: &str =
+    "    --input <path>    read the puzzle input from <path>, or from stdin when <path> is -
+    --example         read the example input under test-inputs/";
+
+impl InputSource {
+    /// Takes `--input <path>` and `--example` out of `args`, leaving the other arguments in place.
+    pub fn extract(args: &mut Vec<String>) -> Result<InputSource> {
+        let mut source = InputSource::Default;
+        let mut index = 0;
+
+        while index < args.len() {
+            let next = match args[index].as_str() {
+                "--example" => {
+                    args.remove(index);
+                    InputSource::Example
+                }
+                "--input" => {
+                    args.remove(index);
+                    if index >= args.len() {
+                        return Err(Error::Usage("--input needs a path".to_string()));
+                    }
+                    match args.remove(index).as_str() {
+                        "-" => InputSource::Stdin,
+                        path => InputSource::File(PathBuf::from(path)),
+                    }
+                }
+                _ => {
+                    index += 1;
+                    continue;
+                }
+            };
+
+            if source != InputSource::Default {
+                return Err(Error::Usage(
+                    "--input and --example can only be given once".to_string(),
+                ));
+            }
+            source = next;
+        }
+
+        Ok(source)
+    }
+
+    /// Reads the input of the given day and part. `default_path` is relative to the workspace root.
+    pub fn read(&self, day: u8, part: u8, default_path: &str) -> Result<Input> {
+        match self {
+            InputSource::Default => Ok(Input {
+                name: default_path.to_string(),
+                text: input::read_to_string(default_path)?,
+            }),
+            InputSource::Example => {
+                let path = example_path(day, part)?;
+                Ok(Input {
+                    name: path.display().to_string(),
+                    text: input::read_to_string(path)?,
+                })
+            }
+            InputSource::File(path) => Ok(Input {
+                name: path.display().to_string(),
+                text: input::read_file(path)?,
+            }),
+            InputSource::Stdin => {
+                let mut raw = String::new();
+                io::stdin()
+                    .read_to_string(&mut raw)
+                    .map_err(|source| Error::Read {
+                        path: PathBuf::from("<stdin>"),
+                        source,
+                    })?;
+                Ok(Input {
+                    name: "<stdin>".to_string(),
+                    text: raw.replace("\r\n", "\n"),
+                })
+            }
+        }
+    }
+}
+
+pub const EXPLAIN_USAGE: &str =
+    "    --explain         print how the parts see each line of the input to stderr, for the days
+                      that can explain it";
+
+/// A puzzle input together with the name of the file it came from.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub name: String,
+    pub text: String,
+}
+
+impl Input {
+    /// Runs `solve` on the input, pointing parse errors at the input file.
+    pub fn solve(&self, solve: impl FnOnce(&str) -> Result<Answer>) -> Result<Answer> {
+        solve(&self.text).map_err(|error| error.in_file(&self.name))
+    }
+}
+
+/// `test-inputs/{day}-{part}.txt` when a part has its own example, `test-inputs/{day}.txt` otherwise.
+pub fn example_path(day: u8, part: u8) -> Result<PathBuf> {
+    let part_specific = Path::new("test-inputs").join(format!("{}-{}.txt", day, part));
+    if input::input_path(&part_specific)?.exists() {
+        return Ok(part_specific);
+    }
+    Ok(Path::new("test-inputs").join(format!("{}.txt", day)))
+}
+
+/// Entry point of a day's own binary. Runs the part given on the command line, or both parts,
+/// and prints each answer after the matching one of `labels`, or as JSON with `--format json`.
+pub fn run_day<S: Solution>(labels: [&str; 2]) -> Result<()> {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let program = std::env::args()
+        .next()
+        .as_deref()
+        .and_then(|arg0| Path::new(arg0).file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let options = [
+        INPUT_USAGE,
+        FORMAT_USAGE,
+        EXPLAIN_USAGE,
+        PARAM_USAGE,
+        LOG_USAGE,
+    ];
+    let usage = format!(
+        "Usage: {} [1 | 2] [--input <path> | --example] [<options>]\n\nOptions:\n{}",
+        program,
+        options.join("\n")
+    );
+    let with_usage = |error| match error {
+        Error::Usage(message) => Error::Usage(format!("{}\n\n{}", message, usage)),
+        other => other,
+    };
+
+    let source = InputSource::extract(&mut args).map_err(with_usage)?;
+    let format = Format::extract(&mut args).map_err(with_usage)?;
+    LogConfig::extract(&mut args).map_err(with_usage)?.init()?;
+    let overrides = params::extract(&mut args).map_err(with_usage)?;
+    params::check_overrides(&overrides, S::PARAMS, &format!("day {}", S::DAY))
+        .map_err(with_usage)?;
+    let explain = match args.iter().position(|arg| arg == "--explain") {
+        Some(index) => {
+            args.remove(index);
+            true
+        }
+        None => false,
+    };
+    let parts = match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        [] => vec![1, 2],
+        ["1"] => vec![1],
+        ["2"] => vec![2],
+        [unknown, ..] => {
+            return Err(Error::Usage(format!(
+                "unrecognized argument {:?}\n\n{}",
+                unknown, usage
+            )));
+        }
+    };
+
+    // Stdin can only be read once, so an input given on the command line is shared by both parts.
+    let shared_input = match source {
+        InputSource::File(_) | InputSource::Stdin => {
+            Some(source.read(S::DAY, parts[0], S::INPUT_PATH)?)
+        }
+        _ => None,
+    };
+
+    let mut explained: Option<String> = None;
+    for part in parts {
+        let input = match &shared_input {
+            Some(input) => input.clone(),
+            None => source.read(S::DAY, part, S::INPUT_PATH)?,
+        };
+        // Both parts usually read the same example.
+        if explain && explained.as_ref() != Some(&input.name) {
+            let parsed = S::parse_with(&input.text, &overrides)
+                .map_err(|error| error.in_file(&input.name))?;
+            let Some(explanation) = S::explain(&parsed) else {
+                return Err(Error::Usage(format!(
+                    "day {} cannot explain its input\n\n{}",
+                    S::DAY,
+                    usage
+                )));
+            };
+            eprintln!("{}", explanation);
+            explained = Some(input.name.clone());
+        }
+        let start = Instant::now();
+        let answer = match part {
+            1 => input.solve(|text| S::part1(&S::parse_with(text, &overrides)?)),
+            _ => input.solve(|text| S::part2(&S::parse_with(text, &overrides)?)),
+        };
+        let elapsed = start.elapsed();
+
+        if format == Format::Json {
+            let reported = answer.as_ref().cloned().map_err(Error::to_string);
+            println!(
+                "{}",
+                Report::new(S::DAY, part, &input, reported, elapsed).to_json()
+            );
+        }
+        let answer = answer?;
+        if format == Format::Text {
+            println!("{}{}", labels[part as usize - 1], answer);
+        }
+    }
+
+    Ok(())
+}
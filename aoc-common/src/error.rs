@@ -0,0 +1,106 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+use crate::parse::ParseError;
+
+/// Exit code used when the project root or a file cannot be read or written.
+pub const EXIT_INPUT_ERROR: i32 = 1;
+/// Exit code used when the input was read but is not in the expected format.
+pub const EXIT_PARSE_ERROR: i32 = 2;
+/// Exit code used when the command line arguments are invalid.
+pub const EXIT_USAGE_ERROR: i32 = 3;
+/// Exit code used when the input parsed fine but the puzzle has no answer for it.
+pub const EXIT_NO_ANSWER: i32 = 4;
+/// Exit code used by `aoc run` when a solution failed or gave another answer than recorded.
+pub const EXIT_CHECK_FAILED: i32 = 5;
+
+#[derive(Debug)]
+pub enum Error {
+    ProjectRoot(io::Error),
+    Read { path: PathBuf, source: io::Error },
+    Write { path: PathBuf, source: io::Error },
+    Parse(ParseError),
+    Usage(String),
+    NoAnswer(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::ProjectRoot(_) | Error::Read { .. } | Error::Write { .. } => EXIT_INPUT_ERROR,
+            Error::Parse(_) => EXIT_PARSE_ERROR,
+            Error::Usage(_) => EXIT_USAGE_ERROR,
+            Error::NoAnswer(_) => EXIT_NO_ANSWER,
+        }
+    }
+
+    /// Records the file the input was read from, for errors that point into the input.
+    pub fn in_file(self, file: impl Into<String>) -> Error {
+        match self {
+            Error::Parse(error) => Error::Parse(error.in_file(file)),
+            other => other,
+        }
+    }
+
+    /// Prints the error to stderr and terminates the process with its exit code.
+    pub fn exit(&self) -> ! {
+        eprintln!("{}", self);
+        std::process::exit(self.exit_code());
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ProjectRoot(error) => write!(f, "Couldn't locate project root dir: {}", error),
+            Error::Read { path, source } => {
+                write!(
+                    f,
+                    "Error while reading input {}: {}",
+                    path.display(),
+                    source
+                )
+            }
+            Error::Write { path, source } => {
+                write!(f, "Error while writing {}: {}", path.display(), source)
+            }
+            Error::Parse(error) => write!(f, "Error while parsing input: {}", error),
+            Error::Usage(message) => write!(f, "{}", message),
+            Error::NoAnswer(message) => write!(f, "No answer: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::ProjectRoot(error) => Some(error),
+            Error::Read { source, .. } | Error::Write { source, .. } => Some(source),
+            Error::Parse(error) => Some(error),
+            Error::Usage(_) | Error::NoAnswer(_) => None,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+/// Unwraps a result or reports the error and exits with the matching exit code.
+pub trait OrExit<T> {
+    fn or_exit(self) -> T;
+}
+
+impl<T, E: Into<Error>> OrExit<T> for std::result::Result<T, E> {
+    fn or_exit(self) -> T {
+        match self {
+            Ok(value) => value,
+            Err(error) => error.into().exit(),
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use std::fmt;
+
+/// A position on a grid, `x` being the column and `y` the row, both counted from the top left
+/// corner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub const fn new(x: usize, y: usize) -> Point {
+        Point { x, y }
+    }
+
+    /// Moves by `(dx, dy)`, or returns `None` when either coordinate would go below zero or
+    /// overflow.
+    pub fn checked_add_signed(self, (dx, dy): (isize, isize)) -> Option<Point> {
+        Some(Point {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+
+    /// The point one step away in `direction`, `None` when it would be left of or above the
+    /// origin.
+    pub fn step(self, direction: impl Into<Direction8>) -> Option<Point> {
+        self.checked_add_signed(direction.into().offset())
+    }
+
+    /// The offset that leads from `self` to `other`.
+    pub fn signed_offset_to(self, other: Point) -> (i64, i64) {
+        (
+            other.x as i64 - self.x as i64,
+            other.y as i64 - self.y as i64,
+        )
+    }
+
+    /// Like [`Point::checked_add_signed`], for offsets computed with [`Point::signed_offset_to`].
+    pub fn checked_add_offset(self, (dx, dy): (i64, i64)) -> Option<Point> {
+        let x = (self.x as i64).checked_add(dx)?;
+        let y = (self.y as i64).checked_add(dy)?;
+        Point::try_from((x, y)).ok()
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Point {
+        Point { x, y }
+    }
+}
+
+impl From<Point> for (usize, usize) {
+    fn from(point: Point) -> (usize, usize) {
+        (point.x, point.y)
+    }
+}
+
+impl TryFrom<(i64, i64)> for Point {
+    type Error = std::num::TryFromIntError;
+
+    /// Fails when either coordinate is negative.
+    fn try_from((x, y): (i64, i64)) -> Result<Point, Self::Error> {
+        Ok(Point {
+            x: usize::try_from(x)?,
+            y: usize::try_from(y)?,
+        })
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// One of the four directions along the rows and columns of a grid, up being towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up = 0,
+    Right = 1,
+    Down = 2,
+    Left = 3,
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Quarter turn counterclockwise.
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The smallest number of quarter turns, in either direction, that face `self` towards
+    /// `other`.
+    pub fn turns_to(self, other: Direction) -> u8 {
+        let right_turns = (other as u8 + 4 - self as u8) % 4;
+        right_turns.min(4 - right_turns)
+    }
+
+    /// The `(dx, dy)` of one step in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        Direction8::from(self).offset()
+    }
+
+    /// `'^'`, `'>'`, `'v'` or `'<'`, the way the puzzles draw arrows.
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+
+    /// The direction an arrow drawn with [`Direction::arrow`] points to.
+    pub fn from_arrow(arrow: char) -> Option<Direction> {
+        match arrow {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+}
+
+/// [`Direction`] extended with the four diagonals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    Up = 0,
+    UpRight = 1,
+    Right = 2,
+    DownRight = 3,
+    Down = 4,
+    DownLeft = 5,
+    Left = 6,
+    UpLeft = 7,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from up.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Eighth turn clockwise.
+    pub fn turn_right(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Eighth turn counterclockwise.
+    pub fn turn_left(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    /// The smallest number of eighth turns, in either direction, that face `self` towards
+    /// `other`.
+    pub fn turns_to(self, other: Direction8) -> u8 {
+        let right_turns = (other as u8 + 8 - self as u8) % 8;
+        right_turns.min(8 - right_turns)
+    }
+
+    /// The `(dx, dy)` of one step in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        }
+    }
+
+    /// `None` for the diagonals.
+    pub fn cardinal(self) -> Option<Direction> {
+        match self {
+            Direction8::Up => Some(Direction::Up),
+            Direction8::Right => Some(Direction::Right),
+            Direction8::Down => Some(Direction::Down),
+            Direction8::Left => Some(Direction::Left),
+            _ => None,
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Direction8 {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
@@ -0,0 +1,369 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::geometry::{Direction, Direction8, Point};
+use crate::input;
+use crate::parse::ParseError;
+
+/// A rectangular grid stored row by row.
+///
+/// Positions are [`Point`]s, `x` being the column and `y` the row, both counted from the top left
+/// corner. Anything that converts into a `Point`, like an `(x, y)` pair, can be passed in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Returns `None` when the rows are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (i64, i64)) -> bool {
+        (0..self.width as i64).contains(&x) && (0..self.height as i64).contains(&y)
+    }
+
+    pub fn get(&self, position: impl Into<Point>) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: impl Into<Point>) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// Like [`Grid::get`], for positions computed with signed arithmetic that may be negative.
+    pub fn get_signed(&self, (x, y): (i64, i64)) -> Option<&T> {
+        if !self.contains((x, y)) {
+            return None;
+        }
+        self.get((x as usize, y as usize))
+    }
+
+    /// Replaces the value at `position`.
+    ///
+    /// Panics when the position is outside the grid like indexing does, use [`Grid::get_mut`] to
+    /// check.
+    pub fn set(&mut self, position: impl Into<Point>, value: T) {
+        self[position.into()] = value;
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every position with its value, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The first position, row by row, whose value matches `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(position, _)| position)
+    }
+
+    /// The position one step from `position` in `direction`, if it is inside the grid.
+    pub fn step(
+        &self,
+        position: impl Into<Point>,
+        direction: impl Into<Direction8>,
+    ) -> Option<Point> {
+        position
+            .into()
+            .step(direction)
+            .filter(|next| next.x < self.width && next.y < self.height)
+    }
+
+    /// The neighbours above, right, below and left of `position` that are inside the grid.
+    pub fn neighbours_4<P: Into<Point>>(
+        &self,
+        position: P,
+    ) -> impl Iterator<Item = Point> + use<T, P> {
+        self.neighbours(position.into(), Direction::ALL.map(Direction8::from))
+    }
+
+    /// Like [`Grid::neighbours_4`], including the diagonal neighbours, clockwise from above.
+    pub fn neighbours_8<P: Into<Point>>(
+        &self,
+        position: P,
+    ) -> impl Iterator<Item = Point> + use<T, P> {
+        self.neighbours(position.into(), Direction8::ALL)
+    }
+
+    fn neighbours<const N: usize>(
+        &self,
+        position: Point,
+        directions: [Direction8; N],
+    ) -> impl Iterator<Item = Point> + use<T, N> {
+        let (width, height) = (self.width, self.height);
+        directions.into_iter().filter_map(move |direction| {
+            position
+                .step(direction)
+                .filter(|next| next.x < width && next.y < height)
+        })
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a chunk size of 0, a grid without columns has no cells anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The column at `x`, top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = if x < self.width {
+            &self.cells[x..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The diagonals running from the top left towards the bottom right, starting from the one
+    /// in the top right corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.width)
+            .rev()
+            .map(|x| (x, 0))
+            .chain((1..self.height).map(|y| (0, y)));
+        starts.map(move |(x, y)| (0..).map_while(move |step| self.get((x + step, y + step))))
+    }
+
+    /// The diagonals running from the top right towards the bottom left, starting from the one
+    /// in the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let right_edge = if self.width > 0 { 1..self.height } else { 0..0 };
+        let starts = (0..self.width)
+            .map(|x| (x, 0))
+            .chain(right_edge.map(|y| (self.width - 1, y)));
+        starts.map(move |(x, y)| (0..=x).map_while(move |step| self.get((x - step, y + step))))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn index_of(&self, position: impl Into<Point>) -> Option<usize> {
+        let Point { x, y } = position.into();
+        (x < self.width && y < self.height).then(|| y * self.width + x)
+    }
+}
+
+impl Grid<char> {
+    /// Parses a rectangular block of characters, one row per line.
+    pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        Ok(Grid::from_checked_rows(input::parse_char_grid(input)?))
+    }
+
+    /// Like [`Grid::parse`], for rows picked out of `input`. Every row has to be a slice of `input`.
+    pub fn parse_rows(input: &str, rows: &[&str]) -> Result<Grid<char>, ParseError> {
+        Ok(Grid::from_checked_rows(input::parse_char_rows(
+            input, rows,
+        )?))
+    }
+
+    /// `rows` has to be non-empty with rows of equal length, as checked by `parse_char_rows`.
+    fn from_checked_rows(rows: Vec<Vec<char>>) -> Grid<char> {
+        Grid {
+            width: rows[0].len(),
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+}
+
+impl<T, P: Into<Point>> Index<P> for Grid<T> {
+    type Output = T;
+
+    /// Panics when the position is outside the grid, use [`Grid::get`] to check.
+    fn index(&self, position: P) -> &T {
+        let Point { x, y } = position.into();
+        match self.index_of((x, y)) {
+            Some(index) => &self.cells[index],
+            None => panic!(
+                "position ({}, {}) is outside the {}x{} grid",
+                x, y, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T, P: Into<Point>> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, position: P) -> &mut T {
+        let Point { x, y } = position.into();
+        let (width, height) = (self.width, self.height);
+        match self.index_of((x, y)) {
+            Some(index) => &mut self.cells[index],
+            None => panic!(
+                "position ({}, {}) is outside the {}x{} grid",
+                x, y, width, height
+            ),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    /// Writes the cells of each row next to each other, one row per line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `abc`, `def` and `ghi`, one row each.
+    fn letters() -> Grid<char> {
+        Grid::parse("abc\ndef\nghi\n").unwrap()
+    }
+
+    fn strings<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn parse_rows_points_at_the_row_of_another_length() {
+        let input = "####\n#..\n####\n";
+        let rows = input.lines().collect::<Vec<_>>();
+
+        let error = Grid::parse_rows(input, &rows).unwrap_err();
+        assert!(matches!(error, ParseError::Invalid { .. }), "{:?}", error);
+        assert_eq!((error.location().line, error.location().column), (2, 1));
+    }
+
+    #[test]
+    fn parse_rows_rejects_no_rows_and_an_empty_first_row() {
+        for rows in [&[][..], &["", "ab"][..]] {
+            let error = Grid::parse_rows("\nab\n", rows).unwrap_err();
+            assert!(
+                matches!(error, ParseError::UnexpectedEnd { .. }),
+                "{:?}",
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn parse_rows_keeps_only_the_given_rows() {
+        let input = "x=1\nab\ncd\n";
+        let rows = input.lines().skip(1).collect::<Vec<_>>();
+
+        let grid = Grid::parse_rows(input, &rows).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid.to_string(), "ab\ncd");
+    }
+
+    #[test]
+    fn diagonals_start_top_right_and_end_bottom_left() {
+        assert_eq!(strings(letters().diagonals()), [
+            "c", "bf", "aei", "dh", "g"
+        ]);
+    }
+
+    #[test]
+    fn anti_diagonals_start_top_left_and_end_bottom_right() {
+        assert_eq!(strings(letters().anti_diagonals()), [
+            "a", "bd", "ceg", "fh", "i"
+        ]);
+    }
+
+    #[test]
+    fn diagonals_of_a_wide_and_an_empty_grid() {
+        let wide = Grid::parse("abc\ndef\n").unwrap();
+        assert_eq!(strings(wide.diagonals()), ["c", "bf", "ae", "d"]);
+        assert_eq!(strings(wide.anti_diagonals()), ["a", "bd", "ce", "f"]);
+
+        let empty = Grid::<char>::from_rows(vec![]).unwrap();
+        assert_eq!(empty.diagonals().count(), 0);
+        assert_eq!(empty.anti_diagonals().count(), 0);
+    }
+
+    #[test]
+    fn step_stays_inside_the_grid() {
+        let grid = letters();
+        let corner = Point::new(2, 2);
+
+        assert_eq!(grid.step((0, 0), Direction::Up), None);
+        assert_eq!(grid.step((0, 0), Direction::Left), None);
+        assert_eq!(grid.step(corner, Direction::Right), None);
+        assert_eq!(grid.step(corner, Direction::Down), None);
+        assert_eq!(grid.step(corner, Direction8::UpRight), None);
+        assert_eq!(grid.step((0, 2), Direction8::DownLeft), None);
+
+        assert_eq!(
+            grid.step(corner, Direction8::UpLeft),
+            Some(Point::new(1, 1))
+        );
+        assert_eq!(grid.step((0, 0), Direction::Right), Some(Point::new(1, 0)));
+    }
+
+    #[test]
+    #[should_panic(expected = "position (3, 0) is outside the 3x3 grid")]
+    fn set_outside_the_grid_panics() {
+        letters().set((3, 0), 'x');
+    }
+
+    #[test]
+    fn neighbours_of_a_corner_are_inside_the_grid() {
+        let grid = letters();
+
+        let neighbours = grid.neighbours_4((0, 0)).collect::<Vec<_>>();
+        assert_eq!(neighbours, [Point::new(1, 0), Point::new(0, 1)]);
+        assert_eq!(grid.neighbours_8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 8);
+    }
+}
@@ -0,0 +1,98 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::parse::{self, ParseError};
+
+/// Resolves a path relative to the workspace root, e.g. `"day-1/input.txt"`.
+pub fn input_path(relative_path: impl AsRef<Path>) -> Result<PathBuf> {
+    let project_root_path = project_root::get_project_root().map_err(Error::ProjectRoot)?;
+    Ok(project_root_path.join(relative_path))
+}
+
+/// Reads the whole input file. Windows line endings are normalized to `\n`.
+pub fn read_to_string(relative_path: impl AsRef<Path>) -> Result<String> {
+    read_file(input_path(relative_path)?)
+}
+
+/// Like [`read_to_string`], but for a path that is not relative to the workspace root.
+pub fn read_file(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    let raw = fs::read_to_string(path).map_err(|source| Error::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    Ok(raw.replace("\r\n", "\n"))
+}
+
+/// Reads the input file as lines without their line endings.
+pub fn read_lines(relative_path: impl AsRef<Path>) -> Result<Vec<String>> {
+    Ok(parse_lines(&read_to_string(relative_path)?))
+}
+
+/// Reads every whitespace-separated token of the input file as a number.
+pub fn read_numbers<T: FromStr>(relative_path: impl AsRef<Path>) -> Result<Vec<T>> {
+    let relative_path = relative_path.as_ref();
+    parse_numbers(&read_to_string(relative_path)?)
+        .map_err(|error| error.in_file(relative_path.display().to_string()).into())
+}
+
+/// Reads the input file as a rectangular grid of characters, indexed `[row][column]`.
+pub fn read_char_grid(relative_path: impl AsRef<Path>) -> Result<Vec<Vec<char>>> {
+    let relative_path = relative_path.as_ref();
+    parse_char_grid(&read_to_string(relative_path)?)
+        .map_err(|error| error.in_file(relative_path.display().to_string()).into())
+}
+
+/// Splits already loaded input into lines without their line endings.
+pub fn parse_lines(input: &str) -> Vec<String> {
+    input.lines().map(str::to_string).collect()
+}
+
+/// Parses every whitespace-separated token of already loaded input as a number.
+pub fn parse_numbers<T: FromStr>(input: &str) -> std::result::Result<Vec<T>, ParseError> {
+    input
+        .split_whitespace()
+        .map(|token| parse::number(input, token))
+        .collect()
+}
+
+/// Parses already loaded input as a rectangular grid of characters, indexed `[row][column]`.
+///
+/// Trailing blank lines are ignored. Every other row must be as long as the first one.
+pub fn parse_char_grid(input: &str) -> std::result::Result<Vec<Vec<char>>, ParseError> {
+    let rows = input
+        .trim_end()
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>();
+
+    parse_char_rows(input, &rows)
+}
+
+/// Like [`parse_char_grid`], for rows picked out of `input`, e.g. the map section of an input
+/// that continues with something else. Every row has to be a slice of `input`.
+pub fn parse_char_rows(
+    input: &str,
+    rows: &[&str],
+) -> std::result::Result<Vec<Vec<char>>, ParseError> {
+    let width = match rows.first() {
+        Some(first) if !first.is_empty() => first.chars().count(),
+        _ => return Err(ParseError::unexpected_end(input, "a row of the grid")),
+    };
+
+    if let Some(row) = rows.iter().find(|row| row.chars().count() != width) {
+        return Err(ParseError::invalid(
+            input,
+            row,
+            format!(
+                "expected every row to be {} characters long like the first one, this one is {}",
+                width,
+                row.chars().count()
+            ),
+        ));
+    }
+
+    Ok(rows.iter().map(|row| row.chars().collect()).collect())
+}
@@ -0,0 +1,292 @@
+//! A local ledger of verified answers, so that any later run on the same input can be checked.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+
+use crate::error::{Error, Result};
+use crate::input;
+use crate::parse::{self, ParseError};
+use crate::report::{Report, json_object, json_string};
+
+/// Where the accepted answers are kept, relative to the workspace root.
+pub const LEDGER_PATH: &str = "answers.json";
+
+/// How an answer compares with the one accepted for the same day, part and input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Matches,
+    Differs,
+    /// No answer was accepted for this input yet.
+    Unknown,
+}
+
+impl Status {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Status::Matches => "matches",
+            Status::Differs => "differs",
+            Status::Unknown => "unknown",
+        }
+    }
+}
+
+/// Day, part and the SHA-256 of the input.
+type Key = (u8, u8, String);
+
+/// Accepted answers keyed by day, part and input, as shown in the Answer column of `aoc run`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ledger {
+    answers: BTreeMap<Key, String>,
+}
+
+impl Ledger {
+    pub fn new() -> Ledger {
+        Ledger::default()
+    }
+
+    /// The ledger in the workspace root. A missing file is an empty ledger.
+    pub fn read() -> Result<Ledger> {
+        let path = input::input_path(LEDGER_PATH)?;
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Ledger::new()),
+            Err(source) => return Err(Error::Read { path, source }),
+        };
+
+        Ledger::parse(&text).map_err(|error| error.in_file(LEDGER_PATH).into())
+    }
+
+    /// Replaces the ledger in the workspace root.
+    pub fn write(&self) -> Result<()> {
+        let path = input::input_path(LEDGER_PATH)?;
+        fs::write(&path, self.to_json()).map_err(|source| Error::Write { path, source })
+    }
+
+    pub fn get(&self, day: u8, part: u8, input_sha256: &str) -> Option<&str> {
+        self.answers
+            .get(&(day, part, input_sha256.to_string()))
+            .map(String::as_str)
+    }
+
+    /// Records `answer` for the input, returning the answer it replaces.
+    pub fn accept(
+        &mut self,
+        day: u8,
+        part: u8,
+        input_sha256: &str,
+        answer: String,
+    ) -> Option<String> {
+        self.answers
+            .insert((day, part, input_sha256.to_string()), answer)
+    }
+
+    /// `None` when the input of the report could not be read. An error differs from any accepted
+    /// answer.
+    pub fn status(&self, report: &Report) -> Option<Status> {
+        let input_sha256 = report.input_sha256.as_deref()?;
+        let status = match (
+            self.get(report.day, report.part, input_sha256),
+            &report.answer,
+        ) {
+            (None, _) => Status::Unknown,
+            (Some(accepted), Ok(answer)) if *accepted == answer.to_string() => Status::Matches,
+            (Some(_), _) => Status::Differs,
+        };
+        Some(status)
+    }
+
+    /// A JSON array with one object per line, sorted by day, part and input.
+    pub fn to_json(&self) -> String {
+        let entries = self
+            .answers
+            .iter()
+            .map(|((day, part, input_sha256), answer)| {
+                json_object(&[
+                    ("day", day.to_string()),
+                    ("part", part.to_string()),
+                    ("input_sha256", json_string(input_sha256)),
+                    ("answer", json_string(answer)),
+                ])
+            })
+            .collect::<Vec<_>>();
+
+        if entries.is_empty() {
+            return "[]\n".to_string();
+        }
+        format!("[\n  {}\n]\n", entries.join(",\n  "))
+    }
+
+    /// Reads the array written by [`Ledger::to_json`]. Only that subset of JSON is supported:
+    /// objects with the four keys in any order, integers for the day and part, and strings
+    /// without `\u` escapes for the rest.
+    pub fn parse(text: &str) -> std::result::Result<Ledger, ParseError> {
+        let mut reader = Reader { text, rest: text };
+        let mut ledger = Ledger::new();
+
+        reader.expect("[", "a JSON array")?;
+        if !reader.eat("]") {
+            loop {
+                let object = reader.rest.trim_start();
+                let (key, answer) = reader.entry()?;
+                if ledger.answers.insert(key.clone(), answer).is_some() {
+                    let object = &object[..object.len() - reader.rest.len()];
+                    return Err(ParseError::invalid(
+                        text,
+                        object,
+                        format!(
+                            "day {} part {} is listed twice for this input",
+                            key.0, key.1
+                        ),
+                    ));
+                }
+                if !reader.eat(",") {
+                    reader.expect("]", "`,` or `]`")?;
+                    break;
+                }
+            }
+        }
+
+        match reader.rest.trim_start() {
+            "" => Ok(ledger),
+            rest => Err(ParseError::unexpected(text, rest, "the end of the ledger")),
+        }
+    }
+}
+
+/// Walks through the ledger, `rest` being what is left of `text`.
+struct Reader<'a> {
+    text: &'a str,
+    rest: &'a str,
+}
+
+impl Reader<'_> {
+    /// Skips whitespace, then `token` when it comes next.
+    fn eat(&mut self, token: &str) -> bool {
+        self.rest = self.rest.trim_start();
+        match self.rest.strip_prefix(token) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn expect(&mut self, token: &str, expected: &str) -> std::result::Result<(), ParseError> {
+        if self.eat(token) {
+            return Ok(());
+        }
+        Err(self.unexpected(expected))
+    }
+
+    /// An error pointing at the next character.
+    fn unexpected(&self, expected: &str) -> ParseError {
+        match self.rest.chars().next() {
+            Some(c) => ParseError::unexpected(self.text, &self.rest[..c.len_utf8()], expected),
+            None => ParseError::unexpected_end(self.text, expected),
+        }
+    }
+
+    /// One `{"day":…,"part":…,"input_sha256":"…","answer":"…"}` object.
+    fn entry(&mut self) -> std::result::Result<(Key, String), ParseError> {
+        self.expect("{", "an object")?;
+        let object = self.rest;
+        let (mut day, mut part, mut input_sha256, mut answer) = (None, None, None, None);
+
+        loop {
+            self.rest = self.rest.trim_start();
+            let key_start = self.rest;
+            let key = self.string()?;
+            let key_text = &key_start[..key_start.len() - self.rest.len()];
+            self.expect(":", "`:`")?;
+            match key.as_str() {
+                "day" => day = Some(self.number()?),
+                "part" => part = Some(self.number()?),
+                "input_sha256" => input_sha256 = Some(self.string()?),
+                "answer" => answer = Some(self.string()?),
+                _ => {
+                    return Err(ParseError::invalid(
+                        self.text,
+                        key_text,
+                        format!("unknown key {:?}", key),
+                    ));
+                }
+            }
+            if !self.eat(",") {
+                self.expect("}", "`,` or `}`")?;
+                break;
+            }
+        }
+
+        let object = &object[..object.len() - self.rest.len() - 1];
+        let missing = |key: &str| ParseError::invalid(self.text, object, format!("no {:?}", key));
+        Ok((
+            (
+                day.ok_or_else(|| missing("day"))?,
+                part.ok_or_else(|| missing("part"))?,
+                input_sha256.ok_or_else(|| missing("input_sha256"))?,
+            ),
+            answer.ok_or_else(|| missing("answer"))?,
+        ))
+    }
+
+    fn number(&mut self) -> std::result::Result<u8, ParseError> {
+        self.rest = self.rest.trim_start();
+        let digits = self.rest.len()
+            - self
+                .rest
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len();
+        if digits == 0 {
+            return Err(self.unexpected("a number"));
+        }
+        let (token, rest) = self.rest.split_at(digits);
+        self.rest = rest;
+        parse::number(self.text, token)
+    }
+
+    /// A string without its quotes and with `\"`, `\\`, `\/`, `\n`, `\r` and `\t` unescaped.
+    fn string(&mut self) -> std::result::Result<String, ParseError> {
+        self.expect("\"", "a string")?;
+        let mut value = String::new();
+        let mut chars = self.rest.char_indices();
+
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.rest = &self.rest[index + 1..];
+                    return Ok(value);
+                }
+                '\\' => {
+                    let escaped = match chars.next() {
+                        Some((_, c @ ('"' | '\\' | '/'))) => c,
+                        Some((_, 'n')) => '\n',
+                        Some((_, 'r')) => '\r',
+                        Some((_, 't')) => '\t',
+                        _ => {
+                            let escape = &self.rest[index..];
+                            let end = escape
+                                .char_indices()
+                                .nth(2)
+                                .map_or(escape.len(), |(end, _)| end);
+                            return Err(ParseError::unexpected(
+                                self.text,
+                                &escape[..end],
+                                "one of the escapes \\\" \\\\ \\/ \\n \\r \\t",
+                            ));
+                        }
+                    };
+                    value.push(escaped);
+                }
+                c => value.push(c),
+            }
+        }
+
+        Err(ParseError::unexpected_end_of(
+            self.text,
+            self.rest,
+            "a closing `\"`",
+        ))
+    }
+}
@@ -0,0 +1,39 @@
+//! Helpers shared by every day of the Advent of Code 2024 solutions.
+
+pub mod answer;
+pub mod bench;
+pub mod cli;
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod ledger;
+pub mod log;
+pub mod params;
+pub mod parse;
+pub mod report;
+pub mod rng;
+pub mod search;
+pub mod sha256;
+pub mod solution;
+pub mod testing;
+
+pub use answer::Answer;
+pub use cli::{Input, InputSource, example_path, run_day};
+pub use error::{
+    EXIT_CHECK_FAILED, EXIT_INPUT_ERROR, EXIT_NO_ANSWER, EXIT_PARSE_ERROR, EXIT_USAGE_ERROR, Error,
+    OrExit, Result,
+};
+pub use geometry::{Direction, Direction8, Point};
+pub use grid::Grid;
+pub use input::{
+    input_path, parse_char_grid, parse_char_rows, parse_lines, parse_numbers, read_char_grid,
+    read_file, read_lines, read_numbers, read_to_string,
+};
+pub use ledger::Ledger;
+pub use log::LogConfig;
+pub use params::{Header, Override, Param};
+pub use parse::{Location, ParseError};
+pub use report::{Format, Report};
+pub use rng::Rng;
+pub use solution::Solution;
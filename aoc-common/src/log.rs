@@ -0,0 +1,184 @@
+//! Diagnostics for following a solution step by step, written to stderr or to a file.
+//!
+//! Nothing is logged by default. `-v` turns on [`debug!`](crate::debug) messages and `-vv` also
+//! [`trace!`](crate::trace) messages. `--log-target` limits them to the given modules, e.g.
+//! `day-15` for only the traces of day 15. The messages are only formatted when they are logged.
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+use crate::error::{Error, Result};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off = 0,
+    /// What a solution found out, a handful of lines per part.
+    Debug = 1,
+    /// Every step of a simulation or a search.
+    Trace = 2,
+}
+
+pub const LOG_USAGE: &str =
+    "    -v, -vv           log debug messages, or also step by step traces, to stderr
+    --log-target <target>
+                      only log the messages of <target>, e.g. day-15, can be repeated
+    --log-file <path> log to <path> instead of stderr";
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+static TARGETS: OnceLock<Vec<String>> = OnceLock::new();
+/// Unset logs to stderr.
+static FILE: OnceLock<Mutex<File>> = OnceLock::new();
+
+/// The logging flags of the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogConfig {
+    pub level: Level,
+    /// Module paths like `day_15`, every module when empty.
+    pub targets: Vec<String>,
+    pub file: Option<PathBuf>,
+}
+
+impl LogConfig {
+    /// Takes `-v`, `-vv`, `--log-target <target>` and `--log-file <path>` out of `args`, leaving
+    /// the other arguments in place.
+    pub fn extract(args: &mut Vec<String>) -> Result<LogConfig> {
+        let mut verbosity = 0;
+        let mut targets = Vec::new();
+        let mut file = None;
+        let mut index = 0;
+
+        while index < args.len() {
+            match args[index].as_str() {
+                "-v" => verbosity += 1,
+                "-vv" => verbosity += 2,
+                flag @ ("--log-target" | "--log-file") => {
+                    let flag = flag.to_string();
+                    args.remove(index);
+                    if index >= args.len() {
+                        return Err(Error::Usage(format!("{} needs a value", flag)));
+                    }
+                    let value = args.remove(index);
+                    if flag == "--log-target" {
+                        targets.push(value.replace('-', "_"));
+                    } else if file.replace(PathBuf::from(value)).is_some() {
+                        return Err(Error::Usage(
+                            "--log-file can only be given once".to_string(),
+                        ));
+                    }
+                    continue;
+                }
+                _ => {
+                    index += 1;
+                    continue;
+                }
+            }
+            args.remove(index);
+        }
+
+        let level = match verbosity {
+            0 => Level::Off,
+            1 => Level::Debug,
+            _ => Level::Trace,
+        };
+        Ok(LogConfig {
+            level,
+            targets,
+            file,
+        })
+    }
+
+    /// Turns logging on for the rest of the process. Only the first call has an effect.
+    pub fn init(self) -> Result<()> {
+        // A later call must not truncate the log file of the first one either.
+        if TARGETS.get().is_some() {
+            return Ok(());
+        }
+        let file = match &self.file {
+            Some(path) => Some(File::create(path).map_err(|source| Error::Write {
+                path: path.clone(),
+                source,
+            })?),
+            None => None,
+        };
+        if TARGETS.set(self.targets).is_ok() {
+            if let Some(file) = file {
+                let _ = FILE.set(Mutex::new(file));
+            }
+            LEVEL.store(self.level as u8, Ordering::Relaxed);
+        }
+        Ok(())
+    }
+}
+
+/// Whether messages of `level` from the module `target` are logged.
+pub fn enabled(level: Level, target: &str) -> bool {
+    if LEVEL.load(Ordering::Relaxed) < level as u8 {
+        return false;
+    }
+
+    let targets = TARGETS.get().map_or(&[][..], Vec::as_slice);
+    targets.is_empty()
+        || targets.iter().any(|wanted| {
+            target == wanted
+                || target
+                    .strip_prefix(wanted.as_str())
+                    .is_some_and(|rest| rest.starts_with("::"))
+        })
+}
+
+/// Writes one message, used by the logging macros after checking [`enabled`]. A message is
+/// written at once, so messages from several threads do not interleave.
+pub fn write(level: Level, target: &str, message: fmt::Arguments) {
+    let level = match level {
+        Level::Off => return,
+        Level::Debug => "debug",
+        Level::Trace => "trace",
+    };
+    let line = format!("[{} {}] {}\n", level, target, message);
+
+    // Logging must not fail the solution, a message that cannot be written is dropped.
+    let _ = match FILE.get() {
+        Some(file) => file
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .write_all(line.as_bytes()),
+        None => io::stderr().lock().write_all(line.as_bytes()),
+    };
+}
+
+/// Logs a message with [`Level::Debug`], formatted like [`format!`].
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug, module_path!()) {
+            $crate::log::write($crate::log::Level::Debug, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+/// Logs a message with [`Level::Trace`], formatted like [`format!`].
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace, module_path!()) {
+            $crate::log::write($crate::log::Level::Trace, module_path!(), format_args!($($arg)*));
+        }
+    };
+}
+
+/// A map to append to a log message, on a line of its own, when the `visualize` feature of the
+/// calling crate is on, and an empty string otherwise. The map is only drawn with the feature.
+#[macro_export]
+macro_rules! drawing {
+    ($map:expr) => {{
+        #[cfg(feature = "visualize")]
+        let drawing = format!("\n{}", $map);
+        #[cfg(not(feature = "visualize"))]
+        let drawing = String::new();
+        drawing
+    }};
+}
@@ -0,0 +1,308 @@
+//! Puzzle parameters: values the puzzle text fixes, such as how often the stones of day 11 blink,
+//! that an input can change with a `name=value` line at its top and the command line with
+//! `--param name=value`. The command line wins over the input, the input over the default.
+
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+use crate::parse::ParseError;
+
+pub const PARAM_USAGE: &str = "    --param <name>=<value>
+                      set a puzzle parameter of the day, can be repeated";
+
+/// A `--param <name>=<value>` of the command line, the name and the value.
+pub type Override = (String, String);
+
+/// A parameter a day reads through [`Header`], listed in [`Solution::PARAMS`].
+///
+/// [`Solution::PARAMS`]: crate::Solution::PARAMS
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    /// What the parameter means, shown by `aoc params`.
+    pub doc: &'static str,
+    /// The value the puzzle uses, written like in an input header.
+    pub default: &'static str,
+}
+
+/// Takes every `--param <name>=<value>` out of `args`, leaving the other arguments in place.
+pub fn extract(args: &mut Vec<String>) -> Result<Vec<Override>> {
+    let mut overrides: Vec<Override> = Vec::new();
+    let mut index = 0;
+
+    while index < args.len() {
+        if args[index] != "--param" {
+            index += 1;
+            continue;
+        }
+
+        args.remove(index);
+        if index >= args.len() {
+            return Err(Error::Usage("--param needs a <name>=<value>".to_string()));
+        }
+        let value = args.remove(index);
+        let Some((name, value)) = value.split_once('=') else {
+            return Err(Error::Usage(format!(
+                "--param needs a <name>=<value>, got {:?}",
+                value
+            )));
+        };
+        if overrides.iter().any(|(known, _)| known == name) {
+            return Err(Error::Usage(format!(
+                "--param {} can only be given once",
+                name
+            )));
+        }
+        overrides.push((name.to_string(), value.to_string()));
+    }
+
+    Ok(overrides)
+}
+
+/// A usage error for the first override that is none of `params`, the parameters of `whose`,
+/// e.g. `"day 11"`.
+pub fn check_overrides(overrides: &[Override], params: &[Param], whose: &str) -> Result<()> {
+    let Some((name, _)) = overrides
+        .iter()
+        .find(|(name, _)| params.iter().all(|param| param.name != name))
+    else {
+        return Ok(());
+    };
+
+    let known = match params {
+        [] => "there are none".to_string(),
+        params => format!(
+            "the parameters are {}",
+            params
+                .iter()
+                .map(|param| param.name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    Err(Error::Usage(format!(
+        "{:?} is not a parameter of {}, {}",
+        name, whose, known
+    )))
+}
+
+/// Reads a range of numbers the way every header writes one, `<min>..=<max>`, or `<min>..` for
+/// no upper end. Meant for [`Header::get_with`].
+pub fn range(value: &str) -> std::result::Result<RangeInclusive<usize>, String> {
+    let expected = || "expected <min>..=<max> or <min>..".to_string();
+    let (min, max) = value.split_once("..").ok_or_else(expected)?;
+    let min = min.parse::<usize>().map_err(|_| expected())?;
+    let max = match max.strip_prefix('=') {
+        Some(max) => max.parse::<usize>().map_err(|_| expected())?,
+        None if max.is_empty() => usize::MAX,
+        None => return Err(expected()),
+    };
+    if min > max {
+        return Err(format!("{}..={} is empty", min, max));
+    }
+    Ok(min..=max)
+}
+
+/// The parameter lines at the top of an input.
+#[derive(Debug, Clone)]
+pub struct Header<'a> {
+    input: &'a str,
+    params: &'a [Param],
+    /// The `--param` names and values of the command line, which win over the header.
+    overrides: &'a [Override],
+    /// Names and values, both slices of `input`.
+    lines: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> Header<'a> {
+    /// Splits the `name=value` lines of `params` off the top of `input`, blank lines in between
+    /// included. Returns them, with `overrides` from the command line in place of their lines,
+    /// and the rest of the input, still a slice of `input`.
+    pub fn split(
+        input: &'a str,
+        params: &'a [Param],
+        overrides: &'a [Override],
+    ) -> std::result::Result<(Header<'a>, &'a str), ParseError> {
+        let mut lines = Vec::new();
+        let mut rest = input;
+
+        for line in input.split_inclusive('\n') {
+            let trimmed = line.trim();
+            if !trimmed.is_empty() {
+                let Some((name, value)) = trimmed.split_once('=') else {
+                    break;
+                };
+                if params.iter().all(|param| param.name != name) {
+                    break;
+                }
+                if lines.iter().any(|&(known, _)| known == name) {
+                    return Err(ParseError::invalid(
+                        input,
+                        trimmed,
+                        format!("{} is set twice", name),
+                    ));
+                }
+                lines.push((name, value));
+            }
+            rest = &rest[line.len()..];
+        }
+
+        Ok((
+            Header {
+                input,
+                params,
+                overrides,
+                lines,
+            },
+            rest,
+        ))
+    }
+
+    /// The value of `name` in the header, a slice of the input, unless the command line overrides
+    /// it. An error about several parameters can point at it.
+    pub fn value_in_input(&self, name: &str) -> Option<&'a str> {
+        if self.overridden(name).is_some() {
+            return None;
+        }
+        self.lines
+            .iter()
+            .find(|&&(known, _)| known == name)
+            .map(|&(_, value)| value)
+    }
+
+    /// The parameter `name` as a number, see [`Header::get_with`].
+    pub fn number<T: FromStr>(&self, name: &str) -> std::result::Result<T, ParseError> {
+        self.get_with(name, |value| {
+            value.parse().map_err(|_| "expected a number".to_string())
+        })
+    }
+
+    /// The parameter `name` from the command line, else from the header, else its default in
+    /// `params`, read with `parse`. `parse` explains why a value is not allowed.
+    pub fn get_with<T>(
+        &self,
+        name: &str,
+        parse: impl Fn(&str) -> std::result::Result<T, String>,
+    ) -> std::result::Result<T, ParseError> {
+        if let Some(value) = self.overridden(name) {
+            // Not a slice of the input, so the error points at its start.
+            return parse(value).map_err(|message| {
+                ParseError::invalid(
+                    self.input,
+                    &self.input[..0],
+                    format!("--param {}={}: {}", name, value, message),
+                )
+            });
+        }
+
+        if let Some(&(_, value)) = self.lines.iter().find(|&&(known, _)| known == name) {
+            return parse(value).map_err(|message| {
+                ParseError::invalid(self.input, value, format!("{}: {}", name, message))
+            });
+        }
+
+        let param = self
+            .params
+            .iter()
+            .find(|param| param.name == name)
+            .unwrap_or_else(|| panic!("{} is not one of the parameters", name));
+        Ok(parse(param.default)
+            .unwrap_or_else(|message| panic!("the default of {} is wrong: {}", name, message)))
+    }
+
+    fn overridden(&self, name: &str) -> Option<&'a str> {
+        self.overrides
+            .iter()
+            .find(|(overridden, _)| overridden == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: &[Param] = &[Param {
+        name: "blinks",
+        doc: "how often the stones blink",
+        default: "25",
+    }];
+
+    const HEADER_PARAMS: &[Param] = &[
+        Param {
+            name: "size",
+            doc: "a size",
+            default: "71",
+        },
+        Param {
+            name: "window",
+            doc: "a window",
+            default: "45..=79",
+        },
+    ];
+
+    #[test]
+    fn overrides_win_over_the_header() {
+        let input = "blinks=6\n";
+        let (header, _) = Header::split(input, PARAMS, &[]).unwrap();
+        assert_eq!(header.number::<u32>("blinks"), Ok(6));
+        assert_eq!(header.value_in_input("blinks"), Some("6"));
+
+        let overrides = [("blinks".to_string(), "75".to_string())];
+        let (header, _) = Header::split(input, PARAMS, &overrides).unwrap();
+        assert_eq!(header.number::<u32>("blinks"), Ok(75));
+        assert_eq!(header.value_in_input("blinks"), None);
+
+        // Every split reads only its own overrides.
+        let overrides = [("blinks".to_string(), "1".to_string())];
+        let (header, _) = Header::split(input, PARAMS, &overrides).unwrap();
+        assert_eq!(header.number::<u32>("blinks"), Ok(1));
+
+        let overrides = [("blinks".to_string(), "many".to_string())];
+        let (header, _) = Header::split(input, PARAMS, &overrides).unwrap();
+        assert!(header.number::<u32>("blinks").is_err());
+    }
+
+    #[test]
+    fn header_lines_are_split_off_the_top() {
+        let input = "size=7\n\nwindow=1..=2\n5,4\nsize=9\n";
+        let (header, rest) = Header::split(input, HEADER_PARAMS, &[]).unwrap();
+
+        assert_eq!(rest, "5,4\nsize=9\n");
+        assert_eq!(header.number::<usize>("size"), Ok(7));
+        assert_eq!(
+            header.get_with("window", |value| Ok(value.to_string())),
+            Ok("1..=2".to_string())
+        );
+    }
+
+    #[test]
+    fn missing_parameters_have_their_default() {
+        let (header, rest) = Header::split("other=1\n5,4\n", HEADER_PARAMS, &[]).unwrap();
+
+        assert_eq!(rest, "other=1\n5,4\n");
+        assert_eq!(header.number::<usize>("size"), Ok(71));
+    }
+
+    #[test]
+    fn header_values_are_checked() {
+        let (header, _) = Header::split("size=seven\n", HEADER_PARAMS, &[]).unwrap();
+        assert!(header.number::<usize>("size").is_err());
+
+        assert!(Header::split("size=7\nsize=8\n", HEADER_PARAMS, &[]).is_err());
+    }
+
+    #[test]
+    fn ranges_include_their_end_or_have_none() {
+        let (header, _) = Header::split("window=3..\n", HEADER_PARAMS, &[]).unwrap();
+        assert_eq!(header.get_with("window", range), Ok(3..=usize::MAX));
+        let (header, _) = Header::split("", HEADER_PARAMS, &[]).unwrap();
+        assert_eq!(header.get_with("window", range), Ok(45..=79));
+
+        assert_eq!(range("1..=1"), Ok(1..=1));
+        assert!(range("2..=1").is_err());
+        assert!(range("1..2").is_err());
+        assert!(range("..=2").is_err());
+    }
+}
@@ -0,0 +1,226 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// Where in the input a parse error happened. Lines and columns start from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    /// The whole line the error is on, used to render the caret.
+    pub line_text: String,
+}
+
+impl Location {
+    /// Locates the byte `offset` of `input`.
+    pub fn at_offset(input: &str, offset: usize) -> Location {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |index| offset + index);
+
+        Location {
+            file: None,
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            line_text: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+
+    /// Locates `part`, which has to be a slice of `input`.
+    ///
+    /// Slices that do not point into `input` are looked up by their text instead.
+    pub fn of(input: &str, part: &str) -> Location {
+        let start = input.as_ptr() as usize;
+        let part_start = part.as_ptr() as usize;
+        let offset = if (start..=start + input.len()).contains(&part_start) {
+            part_start - start
+        } else {
+            input.find(part).unwrap_or(0)
+        };
+        Location::at_offset(input, offset)
+    }
+
+    /// The location just past the last character of `input`.
+    pub fn end_of(input: &str) -> Location {
+        Location::at_offset(input, input.trim_end().len())
+    }
+}
+
+/// Why the input of a day could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// `found` is not what the puzzle format allows at this point.
+    Unexpected {
+        location: Location,
+        expected: String,
+        found: String,
+    },
+    /// The input or the current line ended while `expected` was still missing.
+    UnexpectedEnd {
+        location: Location,
+        expected: String,
+    },
+    /// The tokens are well formed but do not make sense together, e.g. rows of different lengths.
+    Invalid {
+        location: Location,
+        found: String,
+        message: String,
+    },
+}
+
+impl ParseError {
+    /// `found` has to be a slice of `input`.
+    pub fn unexpected(input: &str, found: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::Unexpected {
+            location: Location::of(input, found),
+            expected: expected.into(),
+            found: found.to_string(),
+        }
+    }
+
+    pub fn unexpected_end(input: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::UnexpectedEnd {
+            location: Location::end_of(input),
+            expected: expected.into(),
+        }
+    }
+
+    /// Like [`ParseError::unexpected_end`], for when `part`, a slice of `input`, ended too early.
+    pub fn unexpected_end_of(input: &str, part: &str, expected: impl Into<String>) -> ParseError {
+        let mut location = Location::of(input, part);
+        location.column += part.chars().count();
+        ParseError::UnexpectedEnd {
+            location,
+            expected: expected.into(),
+        }
+    }
+
+    /// `found` has to be a slice of `input`.
+    pub fn invalid(input: &str, found: &str, message: impl Into<String>) -> ParseError {
+        ParseError::Invalid {
+            location: Location::of(input, found),
+            found: found.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn location(&self) -> &Location {
+        match self {
+            ParseError::Unexpected { location, .. }
+            | ParseError::UnexpectedEnd { location, .. }
+            | ParseError::Invalid { location, .. } => location,
+        }
+    }
+
+    /// Records the file the input was read from.
+    pub fn in_file(mut self, file: impl Into<String>) -> ParseError {
+        let file = Some(file.into());
+        match &mut self {
+            ParseError::Unexpected { location, .. }
+            | ParseError::UnexpectedEnd { location, .. }
+            | ParseError::Invalid { location, .. } => location.file = file,
+        }
+        self
+    }
+
+    /// Moves the error to line `line`, for an input read one line at a time and parsed as if
+    /// each line were the whole input.
+    pub fn on_line(mut self, line: usize) -> ParseError {
+        match &mut self {
+            ParseError::Unexpected { location, .. }
+            | ParseError::UnexpectedEnd { location, .. }
+            | ParseError::Invalid { location, .. } => location.line = line,
+        }
+        self
+    }
+
+    fn underline_width(&self) -> usize {
+        match self {
+            ParseError::Unexpected { found, .. } | ParseError::Invalid { found, .. } => {
+                found.lines().next().unwrap_or("").chars().count().max(1)
+            }
+            ParseError::UnexpectedEnd { .. } => 1,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    /// Renders the error like rustc does, with a caret under the offending text.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Unexpected {
+                expected, found, ..
+            } => write!(f, "expected {}, found {:?}", expected, found)?,
+            ParseError::UnexpectedEnd { expected, .. } => {
+                write!(f, "expected {}, found end of line", expected)?
+            }
+            ParseError::Invalid { message, .. } => write!(f, "{}", message)?,
+        }
+
+        let location = self.location();
+        let line_number = location.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        let file = location.file.as_deref().unwrap_or("<input>");
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{}--> {}:{}:{}",
+            gutter, file, location.line, location.column
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", line_number, location.line_text)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(location.column - 1),
+            "^".repeat(self.underline_width())
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `token`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::unexpected(input, token, type_description::<T>()))
+}
+
+fn type_description<T>() -> String {
+    let name = std::any::type_name::<T>();
+    if name.starts_with('u') || name == "usize" {
+        format!("a non-negative number that fits in {}", name)
+    } else if name.starts_with('i') {
+        format!("a number that fits in {}", name)
+    } else {
+        format!("a {}", name)
+    }
+}
+
+/// Checks that every character of `part`, a slice of `input`, is one of `allowed`.
+/// Line breaks are always allowed.
+pub fn expect_chars(
+    input: &str,
+    part: &str,
+    allowed: &str,
+    expected: &str,
+) -> Result<(), ParseError> {
+    match part
+        .char_indices()
+        .find(|&(_, c)| c != '\n' && !allowed.contains(c))
+    {
+        Some((offset, c)) => Err(ParseError::unexpected(
+            input,
+            &part[offset..offset + c.len_utf8()],
+            expected,
+        )),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,204 @@
+use std::time::Duration;
+
+use crate::answer::Answer;
+use crate::bench::{Bench, Stats};
+use crate::cli::Input;
+use crate::error::{Error, Result};
+use crate::ledger::Status;
+use crate::sha256;
+
+/// How the answers are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    /// One JSON object per solution and line, see [`Report::to_json`].
+    Json,
+}
+
+pub const FORMAT_USAGE: &str =
+    "    --format <format> print the answers as text (the default) or as json, one object per line";
+
+impl Format {
+    /// Takes `--format <format>` out of `args`, leaving the other arguments in place.
+    pub fn extract(args: &mut Vec<String>) -> Result<Format> {
+        let mut format = None;
+        let mut index = 0;
+
+        while index < args.len() {
+            if args[index] != "--format" {
+                index += 1;
+                continue;
+            }
+
+            args.remove(index);
+            if index >= args.len() {
+                return Err(Error::Usage("--format needs text or json".to_string()));
+            }
+            let next = match args.remove(index).as_str() {
+                "text" => Format::Text,
+                "json" => Format::Json,
+                other => {
+                    return Err(Error::Usage(format!(
+                        "--format must be text or json, got {:?}",
+                        other
+                    )));
+                }
+            };
+
+            if format.is_some() {
+                return Err(Error::Usage("--format can only be given once".to_string()));
+            }
+            format = Some(next);
+        }
+
+        Ok(format.unwrap_or(Format::Text))
+    }
+}
+
+/// The outcome of running one part on one input.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    /// The input file, `None` when it could not be read.
+    pub input: Option<String>,
+    pub input_sha256: Option<String>,
+    pub answer: std::result::Result<Answer, String>,
+    pub elapsed: Duration,
+    /// Set when the part was benchmarked, `elapsed` is then the sum of the medians.
+    pub bench: Option<Bench>,
+    /// Whether the answer is the recorded one, `None` when no answer was recorded.
+    pub passed: Option<bool>,
+    /// How the answer compares with the answer ledger, `None` when it was not consulted.
+    pub ledger: Option<Status>,
+}
+
+impl Report {
+    pub fn new(
+        day: u8,
+        part: u8,
+        input: &Input,
+        answer: std::result::Result<Answer, String>,
+        elapsed: Duration,
+    ) -> Report {
+        Report {
+            day,
+            part,
+            input: Some(input.name.clone()),
+            input_sha256: Some(sha256::hex_digest(input.text.as_bytes())),
+            answer,
+            elapsed,
+            bench: None,
+            passed: None,
+            ledger: None,
+        }
+    }
+
+    /// A report for a part that was benchmarked on `input`.
+    pub fn benchmarked(
+        day: u8,
+        part: u8,
+        input: &Input,
+        result: std::result::Result<(Answer, Bench), String>,
+    ) -> Report {
+        match result {
+            Ok((answer, bench)) => Report {
+                bench: Some(bench),
+                ..Report::new(
+                    day,
+                    part,
+                    input,
+                    Ok(answer),
+                    bench.parse.median + bench.solve.median,
+                )
+            },
+            Err(message) => Report::new(day, part, input, Err(message), Duration::ZERO),
+        }
+    }
+
+    /// A report for an input that could not be read.
+    pub fn unread(day: u8, part: u8, message: String) -> Report {
+        Report {
+            day,
+            part,
+            input: None,
+            input_sha256: None,
+            answer: Err(message),
+            elapsed: Duration::ZERO,
+            bench: None,
+            passed: None,
+            ledger: None,
+        }
+    }
+
+    /// The report as a single line JSON object. The answer is always a string, so that numbers
+    /// too large for a double survive. A failed solution has a `null` answer and an `error`.
+    /// A benchmarked one also has `runs` and the `parse` and `solve` statistics, and one checked
+    /// against a recorded answer has `passed`, and one compared with the answer ledger has
+    /// `ledger`.
+    pub fn to_json(&self) -> String {
+        let (answer, error) = match &self.answer {
+            Ok(answer) => (json_string(&answer.to_string()), "null".to_string()),
+            Err(message) => ("null".to_string(), json_string(message)),
+        };
+        let optional =
+            |value: &Option<String>| value.as_deref().map_or("null".to_string(), json_string);
+
+        let mut fields = vec![
+            ("day", self.day.to_string()),
+            ("part", self.part.to_string()),
+            ("answer", answer),
+            ("error", error),
+            ("input", optional(&self.input)),
+            ("input_sha256", optional(&self.input_sha256)),
+            ("elapsed_ns", self.elapsed.as_nanos().to_string()),
+        ];
+        if let Some(bench) = &self.bench {
+            fields.push(("runs", bench.runs.to_string()));
+            fields.push(("parse", stats_json(&bench.parse)));
+            fields.push(("solve", stats_json(&bench.solve)));
+        }
+        if let Some(passed) = self.passed {
+            fields.push(("passed", passed.to_string()));
+        }
+        if let Some(status) = self.ledger {
+            fields.push(("ledger", json_string(status.as_str())));
+        }
+        json_object(&fields)
+    }
+}
+
+fn stats_json(stats: &Stats) -> String {
+    json_object(&[
+        ("min_ns", stats.min.as_nanos().to_string()),
+        ("median_ns", stats.median.as_nanos().to_string()),
+        ("mean_ns", stats.mean.as_nanos().to_string()),
+        ("p95_ns", stats.p95.as_nanos().to_string()),
+    ])
+}
+
+/// `fields` are already formatted as JSON values.
+pub(crate) fn json_object(fields: &[(&str, String)]) -> String {
+    let fields = fields
+        .iter()
+        .map(|(key, value)| format!("{}:{}", json_string(key), value))
+        .collect::<Vec<_>>();
+    format!("{{{}}}", fields.join(","))
+}
+
+pub(crate) fn json_string(value: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if u32::from(c) < 0x20 => quoted.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
@@ -0,0 +1,413 @@
+//! Shortest path searches over any state type, described by its successors.
+//!
+//! Every search records each state's cheapest cost and every predecessor reaching it at that
+//! cost. The result is a predecessor DAG, so callers can walk one optimal path or all of them.
+//! A search stops as soon as every goal at the cheapest goal cost has been found. With a goal
+//! that never matches, it explores everything reachable.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// What a search found, see the module documentation.
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    states: Vec<S>,
+    indices: HashMap<S, usize>,
+    costs: Vec<u64>,
+    predecessors: Vec<Vec<usize>>,
+    goals: Vec<usize>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn new() -> Search<S> {
+        Search {
+            states: Vec::new(),
+            indices: HashMap::new(),
+            costs: Vec::new(),
+            predecessors: Vec::new(),
+            goals: Vec::new(),
+        }
+    }
+
+    /// Returns the index of `state` and whether it was seen for the first time.
+    fn intern(&mut self, state: S, cost: u64) -> (usize, bool) {
+        if let Some(&index) = self.indices.get(&state) {
+            return (index, false);
+        }
+
+        let index = self.states.len();
+        self.states.push(state.clone());
+        self.indices.insert(state, index);
+        self.costs.push(cost);
+        self.predecessors.push(Vec::new());
+        (index, true)
+    }
+
+    /// Records that `from` reaches `to` at `cost`. Returns whether that is cheaper than before.
+    fn relax(&mut self, from: usize, to: usize, cost: u64) -> bool {
+        if cost < self.costs[to] {
+            self.costs[to] = cost;
+            self.predecessors[to] = vec![from];
+            true
+        } else {
+            if cost == self.costs[to] && !self.predecessors[to].contains(&from) {
+                self.predecessors[to].push(from);
+            }
+            false
+        }
+    }
+
+    /// The first goal found.
+    pub fn goal(&self) -> Option<&S> {
+        self.goals.first().map(|&index| &self.states[index])
+    }
+
+    /// Every goal found at the cheapest goal cost.
+    pub fn goals(&self) -> impl Iterator<Item = &S> {
+        self.goals.iter().map(|&index| &self.states[index])
+    }
+
+    pub fn goal_cost(&self) -> Option<u64> {
+        self.goals.first().map(|&index| self.costs[index])
+    }
+
+    /// The cheapest cost `state` was reached at. It is final for the goals and every state on
+    /// the paths to them, other states may only have been reached by a detour when the search
+    /// stopped.
+    pub fn cost(&self, state: &S) -> Option<u64> {
+        self.indices.get(state).map(|&index| self.costs[index])
+    }
+
+    /// The states that reach `state` at its cheapest cost.
+    pub fn predecessors(&self, state: &S) -> impl Iterator<Item = &S> {
+        let predecessors = match self.indices.get(state) {
+            Some(&index) => self.predecessors[index].as_slice(),
+            None => &[],
+        };
+        predecessors.iter().map(|&index| &self.states[index])
+    }
+
+    /// One cheapest path from a start to `state`, both included.
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        let mut index = *self.indices.get(state)?;
+        let mut path = vec![self.states[index].clone()];
+        while let Some(&previous) = self.predecessors[index].first() {
+            path.push(self.states[previous].clone());
+            index = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every cheapest path from a start to `state`. There can be exponentially many of them,
+    /// see [`Search::states_on_paths_to`] for when only the states matter.
+    pub fn paths_to(&self, state: &S) -> Vec<Vec<S>> {
+        let Some(&index) = self.indices.get(state) else {
+            return Vec::new();
+        };
+
+        let mut paths = Vec::new();
+        let mut stack = vec![vec![index]];
+        while let Some(reversed) = stack.pop() {
+            let last = reversed[reversed.len() - 1];
+            if self.predecessors[last].is_empty() {
+                paths.push(
+                    reversed
+                        .iter()
+                        .rev()
+                        .map(|&index| self.states[index].clone())
+                        .collect(),
+                );
+                continue;
+            }
+            for &previous in &self.predecessors[last] {
+                let mut longer = reversed.clone();
+                longer.push(previous);
+                stack.push(longer);
+            }
+        }
+        paths
+    }
+
+    /// Every state on some cheapest path to one of `targets`, the targets included.
+    pub fn states_on_paths_to<'a>(
+        &'a self,
+        targets: impl IntoIterator<Item = &'a S>,
+    ) -> HashSet<&'a S> {
+        let mut seen = HashSet::new();
+        let mut stack = targets
+            .into_iter()
+            .filter_map(|target| self.indices.get(target).copied())
+            .collect::<Vec<_>>();
+
+        while let Some(index) = stack.pop() {
+            if seen.insert(index) {
+                stack.extend(&self.predecessors[index]);
+            }
+        }
+        seen.into_iter().map(|index| &self.states[index]).collect()
+    }
+}
+
+/// Breadth-first search where every step costs 1.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let (index, true) = search.intern(start, 0) {
+            queue.push_back(index);
+        }
+    }
+
+    while let Some(index) = queue.pop_front() {
+        let cost = search.costs[index];
+        if search.goal_cost().is_some_and(|goal_cost| cost > goal_cost) {
+            break;
+        }
+        if is_goal(&search.states[index]) {
+            search.goals.push(index);
+            continue;
+        }
+
+        for next in successors(&search.states[index]) {
+            match search.intern(next, cost + 1) {
+                (next, true) => {
+                    search.predecessors[next].push(index);
+                    queue.push_back(next);
+                }
+                (next, false) => {
+                    search.relax(index, next, cost + 1);
+                }
+            }
+        }
+    }
+
+    search
+}
+
+/// Dijkstra's algorithm, `successors` lists each next state with the cost of the step there.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(starts, successors, |_| 0, is_goal)
+}
+
+/// A* search, [`dijkstra`] guided by a `heuristic` that estimates the remaining cost to a goal.
+///
+/// The heuristic has to be consistent: it never decreases by more than the cost of a step, and
+/// it is 0 at the goals. Otherwise some cheapest paths may be missed.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut search = Search::new();
+    // Ordered by the estimated total cost, then by the cost so far.
+    let mut queue = BinaryHeap::new();
+    let mut expanded = Vec::new();
+    let mut goal_estimate = None;
+
+    for start in starts {
+        if let (index, true) = search.intern(start, 0) {
+            queue.push(Reverse((heuristic(&search.states[index]), 0, index)));
+        }
+    }
+
+    while let Some(Reverse((estimate, cost, index))) = queue.pop() {
+        expanded.resize(search.states.len(), false);
+        if cost > search.costs[index] || expanded[index] {
+            continue;
+        }
+        if goal_estimate.is_some_and(|goal_estimate| estimate > goal_estimate) {
+            break;
+        }
+        expanded[index] = true;
+
+        if is_goal(&search.states[index]) {
+            search.goals.push(index);
+            goal_estimate.get_or_insert(estimate);
+            continue;
+        }
+
+        for (next, step) in successors(&search.states[index]) {
+            let next_cost = cost + step;
+            let (next, is_new) = search.intern(next, next_cost);
+            let improved = if is_new {
+                search.predecessors[next].push(index);
+                true
+            } else {
+                search.relax(index, next, next_cost)
+            };
+
+            if improved {
+                if let Some(expanded) = expanded.get_mut(next) {
+                    *expanded = false;
+                }
+                let estimate = next_cost + heuristic(&search.states[next]);
+                queue.push(Reverse((estimate, next_cost, next)));
+            }
+        }
+    }
+
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two equally cheap ways from `a` through `b` or `c` to `d`, a dearer one through `f`, and
+    /// `g`, which nothing leads to.
+    const EDGES: [(char, char, u64); 7] = [
+        ('a', 'b', 1),
+        ('a', 'c', 1),
+        ('b', 'd', 1),
+        ('c', 'd', 1),
+        ('d', 'e', 1),
+        ('a', 'f', 2),
+        ('f', 'e', 2),
+    ];
+
+    fn successors(state: &char) -> Vec<(char, u64)> {
+        EDGES
+            .iter()
+            .filter(|(from, _, _)| from == state)
+            .map(|&(_, to, cost)| (to, cost))
+            .collect()
+    }
+
+    fn sorted<'a>(states: impl IntoIterator<Item = &'a char>) -> String {
+        let mut states = states.into_iter().copied().collect::<Vec<_>>();
+        states.sort();
+        states.into_iter().collect()
+    }
+
+    #[test]
+    fn ties_give_every_best_path() {
+        let search = dijkstra(['a'], successors, |&state| state == 'e');
+
+        assert_eq!(search.goal_cost(), Some(3));
+        assert_eq!(sorted(search.predecessors(&'d')), "bc");
+        let mut paths = search.paths_to(&'e');
+        paths.sort();
+        assert_eq!(paths, [vec!['a', 'b', 'd', 'e'], vec!['a', 'c', 'd', 'e']]);
+        assert_eq!(sorted(search.states_on_paths_to(search.goals())), "abcde");
+    }
+
+    #[test]
+    fn ties_between_goals_give_every_goal() {
+        let search = dijkstra(['a'], successors, |&state| state == 'b' || state == 'c');
+
+        assert_eq!(search.goal_cost(), Some(1));
+        assert_eq!(sorted(search.goals()), "bc");
+        assert_eq!(sorted(search.states_on_paths_to(search.goals())), "abc");
+    }
+
+    #[test]
+    fn an_unreachable_goal_explores_everything_reachable() {
+        let search = dijkstra(['a'], successors, |&state| state == 'g');
+
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.goal_cost(), None);
+        assert_eq!(search.goals().count(), 0);
+        assert_eq!(search.paths_to(&'g'), Vec::<Vec<char>>::new());
+        assert!(search.states_on_paths_to([&'g']).is_empty());
+        assert_eq!(search.cost(&'e'), Some(3));
+        assert_eq!(search.cost(&'g'), None);
+    }
+
+    #[test]
+    fn several_starts_search_from_the_nearest() {
+        let search = dijkstra(['a', 'f'], successors, |&state| state == 'e');
+
+        assert_eq!(search.goal_cost(), Some(2));
+        assert_eq!(search.path_to(&'e'), Some(vec!['f', 'e']));
+        assert_eq!(search.cost(&'a'), Some(0));
+        assert_eq!(search.cost(&'f'), Some(0));
+        assert_eq!(search.predecessors(&'f').count(), 0);
+    }
+
+    #[test]
+    fn bfs_counts_steps_whatever_they_cost() {
+        let search = bfs(
+            ['a'],
+            |state| successors(state).into_iter().map(|(next, _)| next),
+            |&state| state == 'e',
+        );
+
+        assert_eq!(search.goal_cost(), Some(2));
+        assert_eq!(search.path_to(&'e'), Some(vec!['a', 'f', 'e']));
+    }
+
+    /// Steps on an open 8 by 8 lattice, where moving right costs 1 and moving down 3.
+    fn lattice(&(x, y): &(u64, u64)) -> Vec<((u64, u64), u64)> {
+        [((x + 1, y), 1), ((x, y + 1), 3)]
+            .into_iter()
+            .chain(x.checked_sub(1).map(|x| ((x, y), 1)))
+            .chain(y.checked_sub(1).map(|y| ((x, y), 3)))
+            .filter(|&((x, y), _)| x < 8 && y < 8)
+            .collect()
+    }
+
+    #[test]
+    fn astar_finds_the_costs_dijkstra_does() {
+        let goal: (u64, u64) = (6, 5);
+        let heuristic = |&(x, y): &(u64, u64)| goal.0.abs_diff(x) + 3 * goal.1.abs_diff(y);
+
+        let guided = astar([(1, 1)], lattice, heuristic, |&state| state == goal);
+        let unguided = dijkstra([(1, 1)], lattice, |&state| state == goal);
+
+        assert_eq!(guided.goal_cost(), Some(5 + 3 * 4));
+        assert_eq!(guided.goal_cost(), unguided.goal_cost());
+        assert_eq!(guided.paths_to(&goal).len(), unguided.paths_to(&goal).len());
+        assert!(guided.states.len() < unguided.states.len());
+    }
+
+    #[test]
+    fn searches_stop_at_the_goal() {
+        // An endless line, that only ends because the searches stop at 3.
+        let mut expanded = vec![];
+        let found = bfs(
+            [0u64],
+            |&state| {
+                expanded.push(state);
+                [state + 1]
+            },
+            |&state| state == 3,
+        );
+        assert_eq!(found.goal_cost(), Some(3));
+        assert_eq!(expanded, [0, 1, 2]);
+
+        let mut expanded = vec![];
+        let found = dijkstra(
+            [0u64],
+            |&state| {
+                expanded.push(state);
+                [(state + 1, 1)]
+            },
+            |&state| state == 3,
+        );
+        assert_eq!(found.goal_cost(), Some(3));
+        assert_eq!(expanded, [0, 1, 2]);
+    }
+}
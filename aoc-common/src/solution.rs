@@ -0,0 +1,55 @@
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::params::{Override, Param};
+
+/// Both parts of one day. The input is parsed once and shared by the parts.
+pub trait Solution {
+    const DAY: u8;
+    /// The day's own puzzle input, relative to the workspace root.
+    const INPUT_PATH: &'static str;
+    /// The puzzle parameters [`Solution::parse`] reads, see [`crate::params`].
+    const PARAMS: &'static [Param] = &[];
+    /// Why a part has no reference solution, which `aoc check` shows in place of a comparison.
+    const NO_REFERENCE: &'static str = "brute force is out of reach";
+
+    type Input;
+
+    /// Parses `input`, with the `--param` values of `overrides` in place of the puzzle
+    /// parameters of its header, see [`crate::params::Header::split`].
+    fn parse_with(input: &str, overrides: &[Override]) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Answer>;
+    fn part2(input: &Self::Input) -> Result<Answer>;
+
+    /// Parses `input` with the puzzle parameters of its own header.
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &[])
+    }
+
+    /// A slow but obviously correct solution of part 1, parsing the input itself, which
+    /// `aoc check` compares [`Solution::part1`] with on generated inputs. `None` when brute force
+    /// is out of reach for the part.
+    fn reference_part1(_input: &str) -> Option<Result<Answer>> {
+        None
+    }
+
+    /// Like [`Solution::reference_part1`], for part 2.
+    fn reference_part2(_input: &str) -> Option<Result<Answer>> {
+        None
+    }
+
+    /// A line by line account of how the parts see the input, printed by `--explain` to help
+    /// debug the puzzle's rules. `None` for the days without one.
+    fn explain(_input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    /// Parses `input` and solves part 1, the shape the runner calls solutions in.
+    fn solve_part1(input: &str) -> Result<Answer> {
+        Self::part1(&Self::parse(input)?)
+    }
+
+    /// Like [`Solution::solve_part1`], for part 2.
+    fn solve_part2(input: &str) -> Result<Answer> {
+        Self::part2(&Self::parse(input)?)
+    }
+}
@@ -0,0 +1,19 @@
+//! Helpers for the tests of the days, which compare answers as `aoc run` shows them.
+
+use crate::answer::Answer;
+use crate::error::Result;
+
+/// The answer of `part` to `input`, panicking with the error when it has none.
+pub fn answer(part: fn(&str) -> Result<Answer>, input: &str) -> String {
+    part(input)
+        .unwrap_or_else(|error| panic!("{}", error))
+        .to_string()
+}
+
+/// Like [`answer`], for a reference solution, which the part has to have.
+pub fn reference(part: fn(&str) -> Option<Result<Answer>>, input: &str) -> String {
+    part(input)
+        .expect("the part has a reference solution")
+        .unwrap_or_else(|error| panic!("{}", error))
+        .to_string()
+}
@@ -0,0 +1,81 @@
+//! The expected answers to the example inputs, recorded in `test-inputs/answers.toml`.
+
+use std::collections::BTreeMap;
+
+use aoc_common::{ParseError, Result};
+
+pub const ANSWERS_PATH: &str = "test-inputs/answers.toml";
+
+/// Expected answers keyed by day and part, as shown in the Answer column of `aoc run`.
+pub type Answers = BTreeMap<(u8, u8), String>;
+
+pub fn read_answers() -> Result<Answers> {
+    let manifest = aoc_common::read_to_string(ANSWERS_PATH)?;
+    parse_answers(&manifest).map_err(|error| error.in_file(ANSWERS_PATH).into())
+}
+
+/// Reads the `[day-N]` tables of `part-N = answer` lines. Only the subset of TOML used by the
+/// manifest is supported: comments on their own line, integers and basic strings.
+pub fn parse_answers(manifest: &str) -> std::result::Result<Answers, ParseError> {
+    let mut answers = Answers::new();
+    let mut day = None;
+
+    for line in manifest.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[') {
+            let number = header
+                .strip_prefix("day-")
+                .and_then(|rest| rest.strip_suffix(']'))
+                .and_then(|number| number.parse::<u8>().ok());
+            day = Some(number.ok_or_else(|| ParseError::unexpected(manifest, line, "[day-N]"))?);
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| ParseError::unexpected(manifest, line, "part-N = answer"))?;
+        let (key, value) = (key.trim(), value.trim());
+        let part = key
+            .strip_prefix("part-")
+            .and_then(|number| number.parse::<u8>().ok())
+            .ok_or_else(|| ParseError::unexpected(manifest, key, "part-N"))?;
+        let day = day.ok_or_else(|| ParseError::unexpected(manifest, line, "a [day-N] table"))?;
+        let answer = parse_value(value)
+            .ok_or_else(|| ParseError::unexpected(manifest, value, "an integer or a string"))?;
+
+        if answers.insert((day, part), answer).is_some() {
+            return Err(ParseError::invalid(
+                manifest,
+                key,
+                format!("day {} part {} is listed twice", day, part),
+            ));
+        }
+    }
+
+    Ok(answers)
+}
+
+/// Integers are kept as written, strings lose their quotes and `\"` / `\\` escapes.
+fn parse_value(value: &str) -> Option<String> {
+    let Some(quoted) = value.strip_prefix('"') else {
+        return value.parse::<i128>().ok().map(|number| number.to_string());
+    };
+
+    let mut text = String::new();
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return chars.as_str().is_empty().then_some(text),
+            '\\' => match chars.next()? {
+                escaped @ ('"' | '\\') => text.push(escaped),
+                _ => return None,
+            },
+            c => text.push(c),
+        }
+    }
+    None
+}
@@ -0,0 +1,198 @@
+//! `aoc check`: compares the solutions with their slow reference solutions on generated inputs,
+//! and shrinks an input they disagree on to a small one.
+
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use aoc_common::{Answer, Error, Rng};
+
+use crate::generate::Generator;
+use crate::registry::Entry;
+
+/// How many seeds are tried at each smaller size before shrinking the text itself.
+const SEEDS_PER_SIZE: u64 = 20;
+
+thread_local! {
+    /// Whether the panics of this thread are expected and kept off stderr.
+    static QUIET_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// The result of checking one part.
+pub enum Outcome {
+    /// Every input gave the same answer, or the same error.
+    Agreed {
+        inputs: usize,
+    },
+    NoReference,
+    Mismatch(Mismatch),
+}
+
+/// An input the solution and the reference disagree on.
+pub struct Mismatch {
+    /// `aoc gen` reproduces the input that first showed the mismatch with these.
+    pub size: usize,
+    pub seed: u64,
+    /// As small as shrinking got it.
+    pub input: String,
+    pub answer: String,
+    pub reference: String,
+}
+
+/// Checks `part` of a day on `inputs` generated inputs of a random size up to `max_size`, all
+/// derived from `seed`.
+pub fn check(
+    part: &Entry,
+    generator: &Generator,
+    inputs: usize,
+    max_size: usize,
+    seed: u64,
+) -> Outcome {
+    let mut rng = Rng::new(seed);
+
+    // Panics are expected while shrinking and reported as answers, not on stderr.
+    quiet_panic_hook();
+    QUIET_PANICS.set(true);
+
+    let mut outcome = Outcome::Agreed { inputs };
+    for _ in 0..inputs {
+        let size = rng.between(1, max_size.max(1) as u64) as usize;
+        let seed = rng.next_u64();
+        let input = (generator.generate)(&mut Rng::new(seed), size);
+
+        match compare(part, &input) {
+            Comparison::Same => continue,
+            Comparison::NoReference => outcome = Outcome::NoReference,
+            Comparison::Different(..) => {
+                outcome = Outcome::Mismatch(shrink(part, generator, size, seed, input));
+            }
+        }
+        break;
+    }
+
+    QUIET_PANICS.set(false);
+    outcome
+}
+
+/// Installs, once, a panic hook that stays silent on the threads checking a part and leaves the
+/// panics of every other thread to the hook it replaces.
+fn quiet_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET_PANICS.get() {
+                hook(info);
+            }
+        }));
+    });
+}
+
+enum Comparison {
+    Same,
+    NoReference,
+    /// The answer and the reference answer, as shown by `aoc run`.
+    Different(String, String),
+}
+
+/// Inputs that both sides cannot parse count as the same, so that shrinking keeps to inputs in
+/// the day's format. An input only one side parses is a difference.
+fn compare(part: &Entry, input: &str) -> Comparison {
+    let Some(reference) = run(|| (part.reference)(input)) else {
+        return Comparison::NoReference;
+    };
+    let answer = run(|| Some((part.solve)(input, &[]))).expect("the solution always answers");
+
+    match (answer, reference) {
+        (Attempt::Unparsable, Attempt::Unparsable) => Comparison::Same,
+        (Attempt::Shown(answer), Attempt::Shown(reference)) if answer == reference => {
+            Comparison::Same
+        }
+        (answer, reference) => Comparison::Different(answer.shown(), reference.shown()),
+    }
+}
+
+enum Attempt {
+    /// What `aoc run` shows in the Answer column.
+    Shown(String),
+    Unparsable,
+}
+
+impl Attempt {
+    fn shown(self) -> String {
+        match self {
+            Attempt::Shown(shown) => shown,
+            Attempt::Unparsable => "unparsable".to_string(),
+        }
+    }
+}
+
+/// `None` when there is nothing to run.
+fn run(solve: impl FnOnce() -> Option<aoc_common::Result<Answer>>) -> Option<Attempt> {
+    let shown = match panic::catch_unwind(AssertUnwindSafe(solve)) {
+        Ok(result) => match result? {
+            Ok(answer) => answer.to_string(),
+            Err(Error::Parse(_)) => return Some(Attempt::Unparsable),
+            Err(error) => format!("error: {}", error),
+        },
+        Err(_) => "error: solution panicked".to_string(),
+    };
+    Some(Attempt::Shown(shown))
+}
+
+/// First looks for the smallest generated input that still disagrees, then removes lines and
+/// then characters from it for as long as the disagreement stays.
+fn shrink(part: &Entry, generator: &Generator, size: usize, seed: u64, input: String) -> Mismatch {
+    let fails = |input: &str| matches!(compare(part, input), Comparison::Different(..));
+
+    let (size, seed, mut input) = (1..size)
+        .flat_map(|smaller| {
+            (0..SEEDS_PER_SIZE).map(move |offset| (smaller, seed.wrapping_add(offset)))
+        })
+        .map(|(size, seed)| (size, seed, (generator.generate)(&mut Rng::new(seed), size)))
+        .find(|(_, _, input)| fails(input))
+        .unwrap_or((size, seed, input));
+
+    input = remove_chunks(input, true, &fails);
+    input = remove_chunks(input, false, &fails);
+
+    let Comparison::Different(answer, reference) = compare(part, &input) else {
+        unreachable!("shrinking only keeps inputs that fail");
+    };
+    Mismatch {
+        size,
+        seed,
+        input,
+        answer,
+        reference,
+    }
+}
+
+/// Removes ever smaller chunks of whole lines, or of characters, while `fails` holds.
+fn remove_chunks(input: String, lines: bool, fails: &impl Fn(&str) -> bool) -> String {
+    let mut pieces = if lines {
+        input
+            .split_inclusive('\n')
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    } else {
+        input.chars().map(String::from).collect()
+    };
+
+    let mut chunk = pieces.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        while start < pieces.len() {
+            let end = (start + chunk).min(pieces.len());
+            let candidate = [&pieces[..start], &pieces[end..]].concat().concat();
+            if fails(&candidate) {
+                pieces.drain(start..end);
+            } else {
+                start = end;
+            }
+        }
+        chunk /= 2;
+    }
+
+    pieces.concat()
+}
@@ -0,0 +1,329 @@
+use std::time::Duration;
+
+use aoc_common::cli::INPUT_USAGE;
+use aoc_common::log::{LOG_USAGE, Level};
+use aoc_common::params::{self, PARAM_USAGE};
+use aoc_common::report::FORMAT_USAGE;
+use aoc_common::{Error, Format, InputSource, LogConfig, Override, Result};
+
+pub const USAGE: &str = "\
+Usage:
+    aoc run <day> [<part>] [--input <path> | --example] [<options>]
+                              run one part, or both parts of a day
+    aoc run --all [--example] [<options>]
+                              run every registered solution
+    aoc accept <day> [<part>] [--input <path> | --example]
+                              run one part, or both parts of a day, and record the
+                              answers as the right ones for the input in answers.json
+    aoc accept --all [--example]
+                              do the same for every registered solution
+    aoc list                  list registered solutions
+    aoc params <day>          list the puzzle parameters of the day and their defaults
+    aoc new <day>             create a crate for a new day and register it
+    aoc gen <day> [--size <size>] [--seed <seed>]
+                              print a random input for the day, of about the size of
+                              the real one unless --size is given
+    aoc check <day> [--random <inputs>] [--size <size>] [--seed <seed>]
+                              compare the day with its reference solutions on random
+                              inputs up to <size>, 100 of them by default";
+
+const RUN_USAGE: &str =
+    "    --jobs <jobs>     run up to <jobs> solutions at the same time, 1 by default
+    --timeout <seconds>
+                      give up on a part that takes longer than <seconds>, 300 with
+                      --all and none otherwise. A part given up on keeps running
+                      until aoc exits, so more than <jobs> parts may use a core
+    --bench <runs>    time parsing and solving <runs> times and compare with the
+                      previous benchmark of the same input
+    --threshold <percent>
+                      with --bench, warn when a part got more than <percent> slower
+                      than before, 10 by default";
+
+/// How many random inputs `aoc check` tries without `--random`.
+const DEFAULT_CHECK_INPUTS: usize = 100;
+
+/// How long `aoc run --all` waits for a part without `--timeout`, so that one slow day cannot
+/// hold up the others.
+const DEFAULT_ALL_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Percentage by which a benchmark may be slower than the previous one before it is reported.
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run {
+        day: u8,
+        part: Option<u8>,
+        options: RunOptions,
+    },
+    RunAll {
+        options: RunOptions,
+    },
+    Accept {
+        day: u8,
+        part: Option<u8>,
+        input: InputSource,
+    },
+    AcceptAll {
+        input: InputSource,
+    },
+    List,
+    Params {
+        day: u8,
+    },
+    New {
+        day: u8,
+    },
+    Gen {
+        day: u8,
+        size: Option<usize>,
+        /// A different input on every run when not given.
+        seed: Option<u64>,
+    },
+    Check {
+        day: u8,
+        inputs: usize,
+        /// The largest input size.
+        size: Option<usize>,
+        seed: Option<u64>,
+    },
+}
+
+/// The flags shared by `aoc run <day>` and `aoc run --all`.
+#[derive(Debug, PartialEq)]
+pub struct RunOptions {
+    pub input: InputSource,
+    pub format: Format,
+    /// How many solutions run at the same time.
+    pub jobs: usize,
+    /// How long a part may take before it is reported as failed.
+    pub timeout: Option<Duration>,
+    pub bench: Option<Bench>,
+    pub log: LogConfig,
+    /// `--param` names and values, checked against the parameters of the selected days.
+    pub params: Vec<Override>,
+}
+
+impl RunOptions {
+    /// Whether no flag was given, as required by the commands other than `run` and `accept`.
+    fn is_default(&self) -> bool {
+        self.input == InputSource::Default && self.only_input()
+    }
+
+    /// Whether no flag but the input was given, as required by `accept`.
+    fn only_input(&self) -> bool {
+        self.format == Format::Text
+            && self.jobs == 1
+            && self.timeout.is_none()
+            && self.bench.is_none()
+            && self.log.level == Level::Off
+            && self.params.is_empty()
+    }
+}
+
+/// `--bench` and `--threshold`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bench {
+    pub runs: usize,
+    pub threshold: f64,
+}
+
+pub fn parse_args(args: &[String]) -> Result<Command> {
+    let mut args = args.to_vec();
+    let with_usage = |error| match error {
+        Error::Usage(message) => usage_error(&message),
+        other => other,
+    };
+    let input = InputSource::extract(&mut args).map_err(with_usage)?;
+    let format = Format::extract(&mut args).map_err(with_usage)?;
+    let log = LogConfig::extract(&mut args).map_err(with_usage)?;
+    let params = params::extract(&mut args).map_err(with_usage)?;
+    let jobs = extract_value(&mut args, "--jobs")?;
+    let timeout = extract_value(&mut args, "--timeout")?;
+    let runs = extract_value(&mut args, "--bench")?;
+    let threshold = extract_value(&mut args, "--threshold")?;
+    let size = extract_value(&mut args, "--size")?;
+    let seed = extract_value(&mut args, "--seed")?;
+    let inputs = extract_value(&mut args, "--random")?;
+    let command = args.first().map(String::as_str);
+    if (size.is_some() || seed.is_some()) && !matches!(command, Some("gen" | "check")) {
+        return Err(usage_error(
+            "--size and --seed can only be given to gen and check",
+        ));
+    }
+    if inputs.is_some() && command != Some("check") {
+        return Err(usage_error("--random can only be given to check"));
+    }
+    let size = size
+        .map(|size| parse_count("--size", "elements", &size))
+        .transpose()?;
+    let seed = seed.map(|seed| parse_seed(&seed)).transpose()?;
+
+    let bench = match (runs, threshold) {
+        (Some(runs), threshold) => Some(Bench {
+            runs: parse_count("--bench", "runs", &runs)?,
+            threshold: match threshold {
+                Some(threshold) => parse_threshold(&threshold)?,
+                None => DEFAULT_THRESHOLD,
+            },
+        }),
+        (None, Some(_)) => return Err(usage_error("--threshold can only be given with --bench")),
+        (None, None) => None,
+    };
+    let jobs = match jobs {
+        Some(jobs) => parse_count("--jobs", "jobs", &jobs)?,
+        None => 1,
+    };
+    if jobs > 1 && bench.is_some() {
+        return Err(usage_error(
+            "--jobs cannot be combined with --bench, parts running side by side skew the timings",
+        ));
+    }
+
+    let timeout = timeout
+        .map(|seconds| parse_count("--timeout", "seconds", &seconds))
+        .transpose()?
+        .map(|seconds| Duration::from_secs(seconds as u64));
+
+    let mut options = RunOptions {
+        input,
+        format,
+        jobs,
+        timeout,
+        bench,
+        log,
+        params,
+    };
+    let args = args.iter().map(String::as_str).collect::<Vec<_>>();
+
+    match args.as_slice() {
+        ["run", "--all"] => match options.input {
+            InputSource::Default | InputSource::Example => {
+                options.timeout.get_or_insert(DEFAULT_ALL_TIMEOUT);
+                Ok(Command::RunAll { options })
+            }
+            _ => Err(usage_error("--input cannot be combined with --all")),
+        },
+        ["run", day] => Ok(Command::Run {
+            day: parse_day(day)?,
+            part: None,
+            options,
+        }),
+        ["run", day, part] => Ok(Command::Run {
+            day: parse_day(day)?,
+            part: Some(parse_part(part)?),
+            options,
+        }),
+        ["accept", "--all"] if options.only_input() => match options.input {
+            InputSource::Default | InputSource::Example => Ok(Command::AcceptAll {
+                input: options.input,
+            }),
+            _ => Err(usage_error("--input cannot be combined with --all")),
+        },
+        ["accept", day] if options.only_input() => Ok(Command::Accept {
+            day: parse_day(day)?,
+            part: None,
+            input: options.input,
+        }),
+        ["accept", day, part] if options.only_input() => Ok(Command::Accept {
+            day: parse_day(day)?,
+            part: Some(parse_part(part)?),
+            input: options.input,
+        }),
+        ["list"] if options.is_default() => Ok(Command::List),
+        ["params", day] if options.is_default() => Ok(Command::Params {
+            day: parse_day(day)?,
+        }),
+        ["new", day] if options.is_default() => Ok(Command::New {
+            day: parse_day(day)?,
+        }),
+        ["gen", day] if options.is_default() => Ok(Command::Gen {
+            day: parse_day(day)?,
+            size,
+            seed,
+        }),
+        ["check", day] if options.is_default() => Ok(Command::Check {
+            day: parse_day(day)?,
+            inputs: match inputs {
+                Some(inputs) => parse_count("--random", "inputs", &inputs)?,
+                None => DEFAULT_CHECK_INPUTS,
+            },
+            size,
+            seed,
+        }),
+        _ => Err(usage_error("unrecognized arguments")),
+    }
+}
+
+/// Takes `flag <value>` out of `args`, leaving the other arguments in place.
+fn extract_value(args: &mut Vec<String>, flag: &str) -> Result<Option<String>> {
+    let Some(index) = args.iter().position(|arg| arg == flag) else {
+        return Ok(None);
+    };
+
+    args.remove(index);
+    if index >= args.len() {
+        return Err(usage_error(&format!("{} needs a value", flag)));
+    }
+    let value = args.remove(index);
+    if args.iter().any(|arg| arg == flag) {
+        return Err(usage_error(&format!("{} can only be given once", flag)));
+    }
+    Ok(Some(value))
+}
+
+fn parse_day(raw: &str) -> Result<u8> {
+    match raw.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(usage_error(&format!(
+            "day must be between 1 and 25, got {:?}",
+            raw
+        ))),
+    }
+}
+
+/// The positive number given to `flag`, a number of `what`.
+fn parse_count(flag: &str, what: &str, raw: &str) -> Result<usize> {
+    match raw.parse::<usize>() {
+        Ok(count) if count > 0 => Ok(count),
+        _ => Err(usage_error(&format!(
+            "{} needs a positive number of {}, got {:?}",
+            flag, what, raw
+        ))),
+    }
+}
+
+fn parse_seed(raw: &str) -> Result<u64> {
+    raw.parse::<u64>().map_err(|_| {
+        usage_error(&format!(
+            "--seed needs a number from 0 to {}, got {:?}",
+            u64::MAX,
+            raw
+        ))
+    })
+}
+
+fn parse_threshold(raw: &str) -> Result<f64> {
+    match raw.parse::<f64>() {
+        Ok(threshold) if threshold >= 0.0 => Ok(threshold),
+        _ => Err(usage_error(&format!(
+            "--threshold needs a percentage of 0 or more, got {:?}",
+            raw
+        ))),
+    }
+}
+
+fn parse_part(raw: &str) -> Result<u8> {
+    match raw {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(usage_error(&format!("part must be 1 or 2, got {:?}", raw))),
+    }
+}
+
+pub fn usage_error(message: &str) -> Error {
+    Error::Usage(format!(
+        "{}\n\n{}\n\nOptions:\n{}\n{}\n{}\n{}\n{}",
+        message, USAGE, INPUT_USAGE, FORMAT_USAGE, RUN_USAGE, PARAM_USAGE, LOG_USAGE
+    ))
+}
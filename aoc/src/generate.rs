@@ -0,0 +1,666 @@
+//! Seeded random puzzle inputs in the format of each day, for `aoc gen`, `aoc check` and the
+//! property tests of the parsers.
+
+use std::fmt::Write;
+
+use aoc_common::Rng;
+
+/// Generates the inputs of one day.
+pub struct Generator {
+    pub day: u8,
+    /// What `--size` counts.
+    pub size: &'static str,
+    /// About the size of a real puzzle input.
+    pub default_size: usize,
+    /// The largest size `aoc check` generates, small enough for the reference solutions.
+    pub check_size: usize,
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+pub const GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        size: "pairs of location IDs",
+        default_size: 1000,
+        check_size: 30,
+        generate: location_lists,
+    },
+    Generator {
+        day: 2,
+        size: "reports",
+        default_size: 1000,
+        check_size: 30,
+        generate: reactor_reports,
+    },
+    Generator {
+        day: 3,
+        size: "instructions",
+        default_size: 750,
+        check_size: 30,
+        generate: corrupted_memory,
+    },
+    Generator {
+        day: 4,
+        size: "rows and columns",
+        default_size: 140,
+        check_size: 12,
+        generate: word_search,
+    },
+    Generator {
+        day: 5,
+        size: "updates",
+        default_size: 200,
+        check_size: 12,
+        generate: page_ordering,
+    },
+    Generator {
+        day: 6,
+        size: "rows and columns",
+        default_size: 130,
+        check_size: 12,
+        generate: guard_map,
+    },
+    Generator {
+        day: 7,
+        size: "equations",
+        default_size: 850,
+        check_size: 20,
+        generate: calibration_equations,
+    },
+    Generator {
+        day: 8,
+        size: "rows and columns",
+        default_size: 50,
+        check_size: 12,
+        generate: antenna_map,
+    },
+    Generator {
+        day: 9,
+        size: "files",
+        default_size: 10000,
+        check_size: 30,
+        generate: disk_map,
+    },
+    Generator {
+        day: 10,
+        size: "rows and columns",
+        default_size: 50,
+        check_size: 10,
+        generate: topographic_map,
+    },
+    Generator {
+        day: 11,
+        size: "stones",
+        default_size: 8,
+        check_size: 4,
+        generate: stones,
+    },
+    Generator {
+        day: 12,
+        size: "rows and columns",
+        default_size: 140,
+        check_size: 10,
+        generate: garden_plots,
+    },
+    Generator {
+        day: 13,
+        size: "claw machines",
+        default_size: 320,
+        check_size: 20,
+        generate: claw_machines,
+    },
+    Generator {
+        day: 14,
+        size: "robots",
+        default_size: 500,
+        check_size: 30,
+        generate: robots,
+    },
+    Generator {
+        day: 15,
+        size: "rows and columns",
+        default_size: 50,
+        check_size: 10,
+        generate: warehouse,
+    },
+    Generator {
+        day: 16,
+        size: "rows and columns",
+        default_size: 141,
+        check_size: 15,
+        generate: reindeer_maze,
+    },
+    Generator {
+        day: 17,
+        size: "3-bit numbers printed by the program",
+        default_size: 16,
+        check_size: 16,
+        generate: three_bit_program,
+    },
+    Generator {
+        day: 18,
+        size: "falling bytes",
+        default_size: 3450,
+        check_size: 100,
+        generate: falling_bytes,
+    },
+];
+
+pub fn generator(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.day == day)
+}
+
+/// Two columns of five digit IDs. A third of the right column repeats IDs from the left one, so
+/// that the similarity score of part 2 is not always 0.
+pub fn location_lists(rng: &mut Rng, size: usize) -> String {
+    let left = (0..size)
+        .map(|_| rng.between(10000, 99999))
+        .collect::<Vec<_>>();
+
+    let mut input = String::new();
+    for &id in &left {
+        let right = if rng.chance(1, 3) {
+            *rng.choose(&left)
+        } else {
+            rng.between(10000, 99999)
+        };
+        let _ = writeln!(input, "{}   {}", id, right);
+    }
+    input
+}
+
+/// Reports of 5 to 8 levels that mostly go up or down by 1 to 3, with about one in ten steps of
+/// any size so that some reports are unsafe and some need a level removed.
+pub fn reactor_reports(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let descending = rng.chance(1, 2);
+        let mut level = rng.between(10, 90) as i64;
+        let mut levels = vec![level];
+        for _ in 1..rng.between(5, 8) {
+            let step = if rng.chance(1, 10) {
+                rng.between(0, 6) as i64 - 3
+            } else {
+                rng.between(1, 3) as i64
+            };
+            level = (level + if descending { -step } else { step }).clamp(1, 99);
+            levels.push(level);
+        }
+
+        let levels = levels.iter().map(i64::to_string).collect::<Vec<_>>();
+        let _ = writeln!(input, "{}", levels.join(" "));
+    }
+    input
+}
+
+/// Instructions separated by junk, about one in ten a `do()` or `don't()` and the rest `mul`s.
+/// A fifth of the `mul`s are corrupted so that they do not count.
+pub fn corrupted_memory(rng: &mut Rng, size: usize) -> String {
+    corrupted_memory_with_muls(rng, size).0
+}
+
+/// The numbers of every `mul(X,Y)` that is not corrupted, with the byte offset it starts at.
+pub type Muls = Vec<(usize, (i32, i32))>;
+
+/// Like [`corrupted_memory`], together with the `mul`s in it that are not corrupted.
+pub fn corrupted_memory_with_muls(rng: &mut Rng, size: usize) -> (String, Muls) {
+    const JUNK: &[&str] = &[
+        "", "", "!", "@", "#", "%", "^", "&", "*", "(", ")", "[", "]", "{", "}", "<", ">", ",",
+        " ", "'", "+", "-", "what()", "from()", "mul", "do", "don't",
+    ];
+
+    let (mut input, mut muls) = (String::new(), vec![]);
+    let mut unclosed = false;
+    for _ in 0..size {
+        // A `)` would close the `mul` before it.
+        let junk = loop {
+            let junk = *rng.choose(JUNK);
+            if !(unclosed && junk.starts_with(')')) {
+                break junk;
+            }
+        };
+        input.push_str(junk);
+        let (first, second) = (rng.between(0, 999), rng.between(0, 999));
+        let kind = rng.below(20);
+        unclosed = kind == 5;
+        let instruction = match kind {
+            0 => "do()".to_string(),
+            1 => "don't()".to_string(),
+            2 => format!("mul({},{}]", first, second),
+            3 => format!("mul ( {},{})", first, second),
+            4 => format!("mul({}, {})", first, second),
+            5 => format!("mul({},{}", first, second),
+            _ => {
+                muls.push((input.len(), (first as i32, second as i32)));
+                format!("mul({},{})", first, second)
+            }
+        };
+        input.push_str(&instruction);
+    }
+    input.push('\n');
+    (input, muls)
+}
+
+/// A square of the letters of XMAS only, so that the words cross each other often.
+pub fn word_search(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let grid = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| *rng.choose(&['X', 'M', 'A', 'S']))
+                .collect()
+        })
+        .collect::<Vec<_>>();
+
+    rows_to_string(&grid)
+}
+
+/// Rules for every pair of up to 49 two digit pages, so that the pages of any update can be put
+/// in order, followed by updates of an odd number of pages. About half of the updates are
+/// already in order.
+pub fn page_ordering(rng: &mut Rng, size: usize) -> String {
+    let mut pages = (10..100).collect::<Vec<u64>>();
+    rng.shuffle(&mut pages);
+    pages.truncate(size.clamp(3, 49));
+
+    let mut rules = Vec::new();
+    for (index, before) in pages.iter().enumerate() {
+        for after in &pages[index + 1..] {
+            rules.push((before, after));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let mut input = String::new();
+    for (before, after) in rules {
+        let _ = writeln!(input, "{}|{}", before, after);
+    }
+    input.push('\n');
+
+    for _ in 0..size {
+        let longest = (pages.len().min(23) - 1) / 2;
+        let len = 2 * rng.between(1, longest as u64) as usize + 1;
+        let mut update = pages.clone();
+        rng.shuffle(&mut update);
+        update.truncate(len);
+        if rng.chance(1, 2) {
+            // `pages` is the order the rules describe.
+            update.sort_by_key(|page| pages.iter().position(|other| other == page));
+        }
+
+        let update = update.iter().map(u64::to_string).collect::<Vec<_>>();
+        let _ = writeln!(input, "{}", update.join(","));
+    }
+    input
+}
+
+/// A square lab with obstacles on about one in twenty positions and a guard facing a random
+/// direction. The guard may walk in a loop.
+pub fn guard_map(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut map = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.chance(1, 20) { '#' } else { '.' })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    map[rng.index(size)][rng.index(size)] = *rng.choose(&['^', '>', 'v', '<']);
+
+    rows_to_string(&map)
+}
+
+/// Equations of 2 to 8 numbers from 1 to 99. Half of the test values come from applying random
+/// operators, concatenation among them, from left to right, so that some equations can be solved
+/// in part 1, some only in part 2 and the rest not at all.
+pub fn calibration_equations(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size.max(1) {
+        let numbers = (0..rng.between(2, 8))
+            .map(|_| rng.between(1, 99))
+            .collect::<Vec<_>>();
+        let test_value = if rng.chance(1, 2) {
+            // At most 100 to the 8th power, which fits.
+            numbers[1..]
+                .iter()
+                .fold(numbers[0], |value, &number| match rng.below(3) {
+                    0 => value + number,
+                    1 => value * number,
+                    _ => format!("{}{}", value, number).parse().unwrap(),
+                })
+        } else {
+            rng.between(1, 100_000)
+        };
+
+        let numbers = numbers.iter().map(u64::to_string).collect::<Vec<_>>();
+        let _ = writeln!(input, "{}: {}", test_value, numbers.join(" "));
+    }
+    input
+}
+
+/// A square map with an antenna on about one in twenty-five positions, of twelve frequencies so
+/// that most frequencies have several antennas.
+pub fn antenna_map(rng: &mut Rng, size: usize) -> String {
+    const FREQUENCIES: [char; 12] = ['a', 'b', 'c', 'x', 'A', 'B', 'C', 'X', '0', '1', '2', '9'];
+    let size = size.max(1);
+    let map = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| match rng.chance(1, 25) {
+                    true => *rng.choose(&FREQUENCIES),
+                    false => '.',
+                })
+                .collect()
+        })
+        .collect::<Vec<_>>();
+
+    rows_to_string(&map)
+}
+
+/// Alternating file and free space lengths, files taking 1 to 9 blocks and free spaces 0 to 9.
+pub fn disk_map(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for file in 0..size.max(1) {
+        if file > 0 {
+            input.push(char::from(b'0' + rng.below(10) as u8));
+        }
+        input.push(char::from(b'1' + rng.below(9) as u8));
+    }
+    input.push('\n');
+    input
+}
+
+/// A square map whose heights climb by one towards the bottom right, with about one in four
+/// heights random instead so that the trails branch and break off, and a few '.' that are
+/// impassable.
+pub fn topographic_map(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let map = (0..size)
+        .map(|row| {
+            (0..size)
+                .map(|column| match rng.below(20) {
+                    0 => '.',
+                    1..=5 => char::from(b'0' + rng.below(10) as u8),
+                    _ => char::from(b'0' + ((row + column) % 10) as u8),
+                })
+                .collect()
+        })
+        .collect::<Vec<_>>();
+
+    rows_to_string(&map)
+}
+
+/// Numbers of up to 7 digits, a few of them 0, and a header with up to 20 blinks for part 2 so
+/// that its stones can still be listed one by one.
+pub fn stones(rng: &mut Rng, size: usize) -> String {
+    let blinks = rng.between(0, 20);
+    let stones = (0..size.max(1))
+        .map(|_| match rng.below(10) {
+            0 => 0,
+            digits => rng.below(10u64.pow(digits.min(7) as u32)),
+        })
+        .map(|stone| stone.to_string())
+        .collect::<Vec<_>>();
+    format!("part2_blinks={}\n{}\n", blinks, stones.join(" "))
+}
+
+/// A square garden of five plant types, where most plots take the plant of the plot to the left or
+/// above so that regions of odd shapes grow, some of them inside others.
+pub fn garden_plots(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut garden = vec![vec!['A'; size]; size];
+    for row in 0..size {
+        for column in 0..size {
+            garden[row][column] = match rng.below(5) {
+                0 if row > 0 => garden[row - 1][column],
+                1 | 2 if column > 0 => garden[row][column - 1],
+                _ => *rng.choose(&['A', 'B', 'C', 'D', 'E']),
+            };
+        }
+    }
+
+    rows_to_string(&garden)
+}
+
+/// Buttons moving the claw 10 to 99 on both axes, never both in the same direction as in the
+/// puzzle. Half of the prizes can be won with at most 100 presses of each button, the others are
+/// moved a little so that most of them cannot. A header moves the prizes of part 2 up to 10000
+/// further, so that their presses can still be tried one by one.
+pub fn claw_machines(rng: &mut Rng, size: usize) -> String {
+    let mut input = format!("prize_offset={}\n", rng.below(10001));
+    for machine in 0..size.max(1) {
+        if machine > 0 {
+            input.push('\n');
+        }
+        let [a_x, a_y, b_x, b_y] = loop {
+            let buttons = [(); 4].map(|_| rng.between(10, 99));
+            if buttons[0] * buttons[3] != buttons[1] * buttons[2] {
+                break buttons;
+            }
+        };
+        let (a, b) = (rng.between(0, 100), rng.between(0, 100));
+        let (mut prize_x, mut prize_y) = (a * a_x + b * b_x, a * a_y + b * b_y);
+        if rng.chance(1, 2) {
+            prize_x += rng.between(1, 9);
+            prize_y += rng.below(10);
+        }
+
+        let _ = writeln!(input, "Button A: X+{}, Y+{}", a_x, a_y);
+        let _ = writeln!(input, "Button B: X+{}, Y+{}", b_x, b_y);
+        let _ = writeln!(input, "Prize: X={}, Y={}", prize_x, prize_y);
+    }
+    input
+}
+
+/// Robots anywhere in the area with any velocity up to the size of the area. Half of the inputs
+/// use the 101x103 area of the puzzle, the others set a smaller one with odd sides in a header,
+/// with the tree of part 2 in its bottom right quarter. The tree takes a few of the robots, so
+/// that part 2 finds one in some of the inputs.
+pub fn robots(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    let (width, height) = if rng.chance(1, 2) {
+        (101, 103)
+    } else {
+        let (width, height) = (2 * rng.between(1, 10) + 1, 2 * rng.between(1, 10) + 1);
+        let _ = writeln!(input, "robot_area_size={},{}", width, height);
+        let _ = writeln!(
+            input,
+            "tree_columns={}..\ntree_rows={}..",
+            width / 2,
+            height / 2
+        );
+        (width, height)
+    };
+    let _ = writeln!(
+        input,
+        "tree_robots={}",
+        rng.between(1 + size as u64 / 4, 1 + size as u64 / 2)
+    );
+
+    for _ in 0..size.max(1) {
+        let velocity = |rng: &mut Rng, side: u64| rng.between(0, 2 * side) as i64 - side as i64;
+        let _ = writeln!(
+            input,
+            "p={},{} v={},{}",
+            rng.below(width),
+            rng.below(height),
+            velocity(rng, width),
+            velocity(rng, height)
+        );
+    }
+    input
+}
+
+/// A square warehouse walled in, with walls on about one in ten and boxes on about one in four of
+/// the positions inside, the robot on one of the others, and four moves per position in lines of
+/// 70.
+pub fn warehouse(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let mut map = (0..size)
+        .map(|row| {
+            (0..size)
+                .map(|column| {
+                    let edge = row == 0 || column == 0 || row == size - 1 || column == size - 1;
+                    match rng.below(20) {
+                        _ if edge => '#',
+                        0 | 1 => '#',
+                        2..=6 => 'O',
+                        _ => '.',
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    map[rng.between(1, size as u64 - 2) as usize][rng.between(1, size as u64 - 2) as usize] = '@';
+
+    let mut input = rows_to_string(&map);
+    input.push('\n');
+    let moves = (0..4 * size * size)
+        .map(|_| *rng.choose(&['^', '>', 'v', '<']))
+        .collect::<Vec<_>>();
+    for line in moves.chunks(70) {
+        input.extend(line);
+        input.push('\n');
+    }
+    input
+}
+
+/// A maze with the start in the bottom left and the end in the top right corner. The corridors
+/// form a spanning tree of the cells at odd coordinates, with some extra walls knocked down so
+/// that there are several paths. An even size is rounded up.
+pub fn reindeer_maze(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(5) | 1;
+    let mut maze = vec![vec!['#'; size]; size];
+
+    // Depth-first search from the start, opening the wall to each newly visited cell.
+    let start = (size - 2, 1);
+    maze[start.0][start.1] = '.';
+    let mut stack = vec![start];
+    while let Some(&(row, column)) = stack.last() {
+        let mut neighbours = [(0, 2), (2, 0), (0, -2), (-2, 0)]
+            .into_iter()
+            .filter_map(|(rows, columns)| {
+                let next = (
+                    row.checked_add_signed(rows)?,
+                    column.checked_add_signed(columns)?,
+                );
+                (next.0 < size - 1 && next.1 < size - 1 && maze[next.0][next.1] == '#')
+                    .then_some(next)
+            })
+            .collect::<Vec<_>>();
+        if neighbours.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        rng.shuffle(&mut neighbours);
+        let next = neighbours[0];
+        maze[(row + next.0) / 2][(column + next.1) / 2] = '.';
+        maze[next.0][next.1] = '.';
+        stack.push(next);
+    }
+
+    for (row, tiles) in maze.iter_mut().enumerate().take(size - 1).skip(1) {
+        for (column, tile) in tiles.iter_mut().enumerate().take(size - 1).skip(1) {
+            let between_cells = (row % 2 == 1) != (column % 2 == 1);
+            if between_cells && *tile == '#' && rng.chance(1, 10) {
+                *tile = '.';
+            }
+        }
+    }
+    maze[start.0][start.1] = 'S';
+    maze[1][size - 2] = 'E';
+
+    rows_to_string(&maze)
+}
+
+/// A program shaped like the real puzzle inputs: every iteration prints one number computed from
+/// the lowest bits of register A, then shifts A by three bits until it is 0. Register A is
+/// chosen so that the program prints `size` numbers, at most 20, and the constants so that some
+/// value of register A makes the program print itself.
+pub fn three_bit_program(rng: &mut Rng, size: usize) -> String {
+    let size = size.clamp(1, 20) as u32;
+    let register_a = rng.between(8u64.pow(size - 1), 8u64.pow(size) - 1);
+
+    let program = loop {
+        let (first, second) = (rng.below(8), rng.below(8));
+        // bxl and bxc, in either order.
+        let mut mixing = [[1, second], [4, rng.below(8)]];
+        rng.shuffle(&mut mixing);
+        let program = [
+            [2, 4],
+            [1, first],
+            [7, 5],
+            mixing[0],
+            mixing[1],
+            [0, 3],
+            [5, 5],
+            [3, 0],
+        ]
+        .concat();
+
+        if prints_itself(0, &program, first, second) {
+            break program;
+        }
+    };
+
+    let program = program.iter().map(u64::to_string).collect::<Vec<_>>();
+    format!(
+        "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+        register_a,
+        program.join(",")
+    )
+}
+
+/// Whether register A can be extended by three bits per number of `remaining`, from the last
+/// one, so that the program prints those numbers. The program xors the lowest bits of A with
+/// `first`, then with `second` and with A shifted by the result of the first xor.
+fn prints_itself(register_a: u64, remaining: &[u64], first: u64, second: u64) -> bool {
+    let Some((&last, rest)) = remaining.split_last() else {
+        return true;
+    };
+
+    (0..8).any(|bits| {
+        let register_a = register_a * 8 + bits;
+        let shift = bits ^ first;
+        let printed = (shift ^ second ^ (register_a >> shift)) % 8;
+        register_a != 0 && printed == last && prints_itself(register_a, rest, first, second)
+    })
+}
+
+/// Distinct positions on the memory space, never the start or the exit, about two thirds of it
+/// as in the puzzle. That is the puzzle's 71x71 space for inputs as large as the puzzle's, and a
+/// smaller one set in a header with a random number of fallen bytes for part 1 otherwise.
+pub fn falling_bytes(rng: &mut Rng, size: usize) -> String {
+    let side = (5..71)
+        .find(|side| side * side - 2 >= size * 3 / 2)
+        .unwrap_or(71);
+    let mut input = String::new();
+    if side < 71 {
+        let _ = writeln!(
+            input,
+            "memory_size={}\nfallen_bytes={}",
+            side,
+            rng.below(size as u64 + 1)
+        );
+    }
+
+    let mut positions = (0..side)
+        .flat_map(|y| (0..side).map(move |x| (x, y)))
+        .filter(|&position| position != (0, 0) && position != (side - 1, side - 1))
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut positions);
+    for (x, y) in positions.into_iter().take(size) {
+        let _ = writeln!(input, "{},{}", x, y);
+    }
+    input
+}
+
+fn rows_to_string(rows: &[Vec<char>]) -> String {
+    let mut input = String::new();
+    for row in rows {
+        input.extend(row);
+        input.push('\n');
+    }
+    input
+}
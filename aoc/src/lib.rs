@@ -0,0 +1,6 @@
+//! The solutions of every day and part, shared by the `aoc` binary and its tests.
+
+pub mod answers;
+pub mod check;
+pub mod generate;
+pub mod registry;
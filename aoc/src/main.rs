@@ -0,0 +1,592 @@
+//! Runs any day and part of the Advent of Code 2024 solutions from one binary.
+
+mod cli;
+mod scaffold;
+mod table;
+
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_common::bench::{self, Stats};
+use aoc_common::ledger::{Ledger, Status};
+use aoc_common::params;
+use aoc_common::{
+    EXIT_CHECK_FAILED, Format, Input, InputSource, OrExit, Override, Param, Report, Rng,
+};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use aoc::answers::{self, Answers};
+use aoc::check::{self, Outcome};
+use aoc::generate;
+use aoc::registry::{self, Entry};
+use cli::{Command, RunOptions};
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    match cli::parse_args(&args).or_exit() {
+        Command::List => list(),
+        Command::Params { day } => list_params(day),
+        Command::Gen { day, size, seed } => generate(day, size, seed),
+        Command::Check {
+            day,
+            inputs,
+            size,
+            seed,
+        } => check(day, inputs, size, seed),
+        Command::New { day } => {
+            scaffold::new_day(day).or_exit();
+            println!("Created day-{0}, solve it in day-{0}/src/lib.rs", day);
+        }
+        Command::RunAll { options } => {
+            run(&registry::SOLUTIONS.iter().collect::<Vec<_>>(), &options)
+        }
+        Command::Run { day, part, options } => run(&select(day, part), &options),
+        Command::AcceptAll { input } => {
+            accept(&registry::SOLUTIONS.iter().collect::<Vec<_>>(), &input)
+        }
+        Command::Accept { day, part, input } => accept(&select(day, part), &input),
+    }
+}
+
+/// The solutions of the day, or only of the part when one is given.
+fn select(day: u8, part: Option<u8>) -> Vec<&'static Entry> {
+    let selected = registry::SOLUTIONS
+        .iter()
+        .filter(|solution| solution.day == day)
+        .filter(|solution| part.is_none_or(|part| solution.part == part))
+        .collect::<Vec<_>>();
+
+    if selected.is_empty() {
+        let missing = match part {
+            Some(part) => format!("day {} part {} has no solution", day, part),
+            None => format!("day {} has no solution", day),
+        };
+        cli::usage_error(&missing).exit();
+    }
+    selected
+}
+
+fn list() {
+    let rows = registry::SOLUTIONS
+        .iter()
+        .map(|solution| {
+            vec![
+                solution.day.to_string(),
+                solution.part.to_string(),
+                solution.input_path.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    table::print_table(&["Day", "Part", "Input"], &rows);
+}
+
+fn list_params(day: u8) {
+    let params = select(day, None)[0].params;
+    if params.is_empty() {
+        println!("day {} has no puzzle parameters", day);
+        return;
+    }
+
+    let rows = params
+        .iter()
+        .map(|param| {
+            vec![
+                param.name.to_string(),
+                param.default.to_string(),
+                param.doc.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    table::print_table(&["Name", "Default", "Meaning"], &rows);
+}
+
+fn generate(day: u8, size: Option<usize>, seed: Option<u64>) {
+    let Some(generator) = generate::generator(day) else {
+        cli::usage_error(&format!("day {} has no input generator", day)).exit();
+    };
+    let size = size.unwrap_or(generator.default_size);
+    let seed = seed.unwrap_or_else(|| {
+        let seed = Rng::random_seed();
+        eprintln!(
+            "generating day {} with {} {} and --seed {}",
+            day, size, generator.size, seed
+        );
+        seed
+    });
+
+    print!("{}", (generator.generate)(&mut Rng::new(seed), size));
+}
+
+fn check(day: u8, inputs: usize, size: Option<usize>, seed: Option<u64>) {
+    let Some(generator) = generate::generator(day) else {
+        cli::usage_error(&format!("day {} has no input generator", day)).exit();
+    };
+    let size = size.unwrap_or(generator.check_size);
+    let seed = seed.unwrap_or_else(Rng::random_seed);
+    println!(
+        "Checking day {} on {} inputs of up to {} {}, --seed {}",
+        day, inputs, size, generator.size, seed
+    );
+
+    let mut failed = false;
+    for part in registry::SOLUTIONS.iter().filter(|part| part.day == day) {
+        match check::check(part, generator, inputs, size, seed) {
+            Outcome::Agreed { inputs } => {
+                println!(
+                    "part {}: same answers as the reference on {} inputs",
+                    part.part, inputs
+                )
+            }
+            Outcome::NoReference => {
+                println!(
+                    "part {}: no reference solution, {}",
+                    part.part, part.no_reference
+                )
+            }
+            Outcome::Mismatch(mismatch) => {
+                failed = true;
+                println!(
+                    "part {}: answers {:?} where the reference answers {:?} on this input, \
+                     shrunk from `aoc gen {} --size {} --seed {}`:\n{}",
+                    part.part,
+                    mismatch.answer,
+                    mismatch.reference,
+                    day,
+                    mismatch.size,
+                    mismatch.seed,
+                    mismatch.input
+                );
+            }
+        }
+    }
+
+    if failed {
+        std::process::exit(EXIT_CHECK_FAILED);
+    }
+}
+
+fn run(solutions: &[&'static Entry], options: &RunOptions) {
+    options.log.clone().init().or_exit();
+    let mut known: Vec<Param> = Vec::new();
+    // Both parts of a day list the same parameters.
+    for &param in solutions.iter().flat_map(|solution| solution.params) {
+        if known.iter().all(|known| known.name != param.name) {
+            known.push(param);
+        }
+    }
+    let whose = match solutions {
+        [first, rest @ ..] if rest.iter().all(|solution| solution.day == first.day) => {
+            format!("day {}", first.day)
+        }
+        _ => "any day".to_string(),
+    };
+    params::check_overrides(&options.params, &known, &whose)
+        .map_err(|error| cli::usage_error(&error.to_string()))
+        .or_exit();
+    // The recorded answers belong to the inputs with their own parameters.
+    let compared = options.params.is_empty();
+
+    let source = &options.input;
+    let shared_input = read_shared_input(source, solutions);
+
+    // Only the answers to the examples are recorded.
+    let expected = match source {
+        InputSource::Example if compared => answers::read_answers().unwrap_or_else(|error| {
+            eprintln!("warning: {}", error);
+            Answers::new()
+        }),
+        _ => Answers::new(),
+    };
+    let ledger = if compared {
+        Ledger::read().unwrap_or_else(|error| {
+            eprintln!("warning: {}", error);
+            Ledger::new()
+        })
+    } else {
+        Ledger::new()
+    };
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(options.jobs)
+        .build()
+        .unwrap_or_else(|error| panic!("cannot start {} threads: {}", options.jobs, error));
+
+    let start = Instant::now();
+    let reports = pool.install(|| {
+        solutions
+            .par_iter()
+            .map(|&solution| {
+                let input = read_input(source, &shared_input, solution);
+                let bench = options.bench;
+                // Owned, as a part that times out keeps running on its own thread.
+                let overrides = options.params.clone();
+                let mut report =
+                    with_timeout(solution, input, options.timeout, move |input| match bench {
+                        Some(cli::Bench { runs, .. }) => {
+                            bench_solution(solution, input, &overrides, runs)
+                        }
+                        None => run_solution(solution, input, &overrides),
+                    });
+                report.passed = expected
+                    .get(&(report.day, report.part))
+                    .map(|expected| answer_text(&report) == *expected);
+                if compared {
+                    report.ledger = ledger.status(&report);
+                }
+
+                // Printed as soon as each solution finishes, so a slow one does not hold back
+                // the rest.
+                if options.format == Format::Json {
+                    println!("{}", report.to_json());
+                }
+                report
+            })
+            .collect::<Vec<_>>()
+    });
+    let wall_time = start.elapsed();
+
+    if options.format == Format::Text {
+        match options.bench {
+            Some(_) => print_bench_table(&reports),
+            None => {
+                print_answer_table(&reports);
+                print_summary(&reports, wall_time);
+            }
+        }
+    }
+    if let Some(cli::Bench { threshold, .. }) = options.bench {
+        compare_with_history(&reports, threshold);
+    }
+    if reports.iter().any(failed) {
+        std::process::exit(EXIT_CHECK_FAILED);
+    }
+}
+
+/// Runs the solutions and records their answers in the ledger as the right ones for the input.
+/// A part that fails is left out.
+fn accept(solutions: &[&Entry], source: &InputSource) {
+    let mut ledger = Ledger::read().or_exit();
+    let shared_input = read_shared_input(source, solutions);
+
+    let mut failed = false;
+    for solution in solutions {
+        let report = run_solution(solution, read_input(source, &shared_input, solution), &[]);
+        let (Ok(answer), Some(input), Some(input_sha256)) =
+            (&report.answer, &report.input, &report.input_sha256)
+        else {
+            failed = true;
+            eprintln!(
+                "day {} part {}: nothing recorded, {}",
+                report.day,
+                report.part,
+                answer_text(&report)
+            );
+            continue;
+        };
+
+        let answer = answer.to_string();
+        match ledger.accept(report.day, report.part, input_sha256, answer.clone()) {
+            None => println!(
+                "day {} part {}: recorded {} for {}",
+                report.day, report.part, answer, input
+            ),
+            Some(previous) if previous == answer => println!(
+                "day {} part {}: {} was already recorded for {}",
+                report.day, report.part, answer, input
+            ),
+            Some(previous) => println!(
+                "day {} part {}: recorded {} for {}, replacing {}",
+                report.day, report.part, answer, input, previous
+            ),
+        }
+    }
+
+    ledger.write().or_exit();
+    if failed {
+        std::process::exit(EXIT_CHECK_FAILED);
+    }
+}
+
+/// Stdin can only be read once, so an input given on the command line is read up front and
+/// shared by both parts of the day.
+fn read_shared_input(source: &InputSource, solutions: &[&Entry]) -> Option<Result<Input, String>> {
+    match (source, solutions.first()) {
+        (InputSource::File(_) | InputSource::Stdin, Some(first)) => Some(
+            source
+                .read(first.day, first.part, first.input_path)
+                .map_err(|error| error.to_string()),
+        ),
+        _ => None,
+    }
+}
+
+fn read_input(
+    source: &InputSource,
+    shared_input: &Option<Result<Input, String>>,
+    solution: &Entry,
+) -> Result<Input, String> {
+    match shared_input {
+        Some(input) => input.clone(),
+        None => source
+            .read(solution.day, solution.part, solution.input_path)
+            .map_err(|error| error.to_string()),
+    }
+}
+
+/// A part fails when it gives another answer than the recorded one or the one in the ledger, or
+/// an error when no answer was recorded.
+fn failed(report: &Report) -> bool {
+    if report.ledger == Some(Status::Differs) {
+        return true;
+    }
+    match report.passed {
+        Some(passed) => !passed,
+        None => report.answer.is_err(),
+    }
+}
+
+fn print_answer_table(reports: &[Report]) {
+    let rows = reports
+        .iter()
+        .map(|report| {
+            vec![
+                report.day.to_string(),
+                report.part.to_string(),
+                answer_cell(report),
+                format!("{:.2?}", report.elapsed),
+                check_cell(report),
+                report.ledger.map_or("-", Status::as_str).to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    table::print_table(&["Day", "Part", "Answer", "Time", "Check", "Ledger"], &rows);
+    print_diagnostics(reports);
+}
+
+/// `wall_time` is less than the sum of the times when solutions ran side by side.
+fn print_summary(reports: &[Report], wall_time: Duration) {
+    let failures = reports.iter().filter(|report| failed(report)).count();
+    let solving = reports
+        .iter()
+        .map(|report| report.elapsed)
+        .sum::<Duration>();
+
+    println!();
+    println!(
+        "{} of {} parts failed, solving took {:.2?}, {:.2?} in total",
+        failures,
+        reports.len(),
+        solving,
+        wall_time
+    );
+}
+
+/// One row for parsing and one for solving per part.
+fn print_bench_table(reports: &[Report]) {
+    let stats_row = |report: &Report, step: &str, stats: &Stats| {
+        vec![
+            report.day.to_string(),
+            report.part.to_string(),
+            step.to_string(),
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.mean),
+            format!("{:.2?}", stats.p95),
+        ]
+    };
+
+    let rows = reports
+        .iter()
+        .flat_map(|report| match &report.bench {
+            Some(bench) => vec![
+                stats_row(report, "parse", &bench.parse),
+                stats_row(report, "solve", &bench.solve),
+            ],
+            None => vec![vec![
+                report.day.to_string(),
+                report.part.to_string(),
+                answer_cell(report),
+            ]],
+        })
+        .collect::<Vec<_>>();
+
+    table::print_table(
+        &["Day", "Part", "Step", "Min", "Median", "Mean", "P95"],
+        &rows,
+    );
+    print_diagnostics(reports);
+}
+
+/// The errors that do not fit in a table cell, like the diagnostics of a parse error with the
+/// offending line and a caret under it.
+fn print_diagnostics(reports: &[Report]) {
+    for report in reports {
+        if let Err(message) = &report.answer {
+            if message.contains('\n') {
+                println!();
+                println!(
+                    "day {} part {}: error: {}",
+                    report.day, report.part, message
+                );
+            }
+        }
+    }
+}
+
+/// `-` when there is no recorded answer to check against.
+fn check_cell(report: &Report) -> String {
+    match report.passed {
+        Some(true) => "pass",
+        Some(false) => "FAIL",
+        None if failed(report) => "FAIL",
+        None => "-",
+    }
+    .to_string()
+}
+
+/// The answer or the whole error message, as recorded in `test-inputs/answers.toml`.
+fn answer_text(report: &Report) -> String {
+    match &report.answer {
+        Ok(answer) => answer.to_string(),
+        Err(message) => format!("error: {}", message),
+    }
+}
+
+/// Like [`answer_text`], with only the first line of an error, the rest is printed below the
+/// table.
+fn answer_cell(report: &Report) -> String {
+    let text = answer_text(report);
+    match text.split_once('\n') {
+        Some((first_line, _)) => first_line.to_string(),
+        None => text,
+    }
+}
+
+/// Warns about every part that got more than `threshold` percent slower than the last time it
+/// was benchmarked on the same input, then records this run in the history.
+fn compare_with_history(reports: &[Report], threshold: f64) {
+    let records = reports
+        .iter()
+        .filter_map(|report| {
+            let bench = report.bench?;
+            Some(bench::Record {
+                day: report.day,
+                part: report.part,
+                input_sha256: report.input_sha256.clone()?,
+                parse_median: bench.parse.median,
+                solve_median: bench.solve.median,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let history = bench::read_history().unwrap_or_else(|error| {
+        eprintln!("warning: {}", error);
+        Vec::new()
+    });
+    for record in &records {
+        let Some(previous) = bench::previous(&history, record) else {
+            continue;
+        };
+        let slowdown = record.slowdown_from(previous);
+        if slowdown > threshold {
+            eprintln!(
+                "warning: day {} part {} got {:.1}% slower than last time ({:.2?} -> {:.2?})",
+                record.day,
+                record.part,
+                slowdown,
+                previous.total(),
+                record.total()
+            );
+        }
+    }
+
+    if let Err(error) = bench::append_history(&records) {
+        eprintln!("warning: {}", error);
+    }
+}
+
+/// Times the solution on an already read input, with the puzzle parameters of `overrides`.
+fn run_solution(solution: &Entry, input: Result<Input, String>, overrides: &[Override]) -> Report {
+    let input = match input {
+        Ok(input) => input,
+        Err(message) => return Report::unread(solution.day, solution.part, message),
+    };
+
+    let start = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        input.solve(|text| (solution.solve)(text, overrides))
+    }));
+    let elapsed = start.elapsed();
+
+    Report::new(
+        solution.day,
+        solution.part,
+        &input,
+        flatten_result(result),
+        elapsed,
+    )
+}
+
+/// Like [`run_solution`], parsing and solving `runs` times.
+fn bench_solution(
+    solution: &Entry,
+    input: Result<Input, String>,
+    overrides: &[Override],
+    runs: usize,
+) -> Report {
+    let input = match input {
+        Ok(input) => input,
+        Err(message) => return Report::unread(solution.day, solution.part, message),
+    };
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        (solution.bench)(&input.text, overrides, runs).map_err(|error| error.in_file(&input.name))
+    }));
+
+    Report::benchmarked(solution.day, solution.part, &input, flatten_result(result))
+}
+
+/// Runs `run` on a thread of its own and, when it takes longer than `timeout`, reports the part
+/// as failed without waiting for it. The thread keeps running until the process exits, beside
+/// the `--jobs` threads of the pool, as `--timeout` warns.
+fn with_timeout(
+    solution: &Entry,
+    input: Result<Input, String>,
+    timeout: Option<Duration>,
+    run: impl FnOnce(Result<Input, String>) -> Report + Send + 'static,
+) -> Report {
+    let Some(timeout) = timeout else {
+        return run(input);
+    };
+
+    let read = input.clone();
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        // Nobody listens any more once the part timed out.
+        let _ = sender.send(run(input));
+    });
+
+    let message = match receiver.recv_timeout(timeout) {
+        Ok(report) => return report,
+        Err(RecvTimeoutError::Timeout) => format!("timed out after {}s", timeout.as_secs()),
+        Err(RecvTimeoutError::Disconnected) => "solution panicked".to_string(),
+    };
+    match read {
+        Ok(input) => Report::new(solution.day, solution.part, &input, Err(message), timeout),
+        Err(unread) => Report::unread(solution.day, solution.part, unread),
+    }
+}
+
+/// Turns errors and panics into the message shown in place of the answer.
+fn flatten_result<T>(result: std::thread::Result<aoc_common::Result<T>>) -> Result<T, String> {
+    match result {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(error)) => Err(error.to_string()),
+        Err(_) => Err("solution panicked".to_string()),
+    }
+}
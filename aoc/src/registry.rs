@@ -0,0 +1,110 @@
+use aoc_common::bench::{self, Bench};
+use aoc_common::{Answer, Override, Param, Result, Solution};
+
+/// Times parsing an input with the `--param` overrides and solving the part, the given number of
+/// times.
+pub type BenchFn = fn(&str, &[Override], usize) -> Result<(Answer, Bench)>;
+
+/// One part of one day, as seen by the runner.
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub input_path: &'static str,
+    /// Parses the input with the `--param` overrides and solves the part.
+    pub solve: fn(&str, &[Override]) -> Result<Answer>,
+    pub bench: BenchFn,
+    /// The slow solution `aoc check` compares `solve` with, see [`Solution::reference_part1`].
+    pub reference: fn(&str) -> Option<Result<Answer>>,
+    /// Why `reference` is `None`, see [`Solution::NO_REFERENCE`].
+    pub no_reference: &'static str,
+    /// The puzzle parameters of the day, see [`Solution::PARAMS`].
+    pub params: &'static [Param],
+}
+
+macro_rules! solution {
+    ($solution:ty, 1) => {
+        Entry {
+            day: <$solution as Solution>::DAY,
+            part: 1,
+            input_path: <$solution as Solution>::INPUT_PATH,
+            solve: |input, overrides| {
+                <$solution as Solution>::part1(&<$solution as Solution>::parse_with(
+                    input, overrides,
+                )?)
+            },
+            bench: |input, overrides, runs| {
+                bench::measure(
+                    input,
+                    runs,
+                    |input| <$solution as Solution>::parse_with(input, overrides),
+                    <$solution as Solution>::part1,
+                )
+            },
+            reference: <$solution as Solution>::reference_part1,
+            no_reference: <$solution as Solution>::NO_REFERENCE,
+            params: <$solution as Solution>::PARAMS,
+        }
+    };
+    ($solution:ty, 2) => {
+        Entry {
+            day: <$solution as Solution>::DAY,
+            part: 2,
+            input_path: <$solution as Solution>::INPUT_PATH,
+            solve: |input, overrides| {
+                <$solution as Solution>::part2(&<$solution as Solution>::parse_with(
+                    input, overrides,
+                )?)
+            },
+            bench: |input, overrides, runs| {
+                bench::measure(
+                    input,
+                    runs,
+                    |input| <$solution as Solution>::parse_with(input, overrides),
+                    <$solution as Solution>::part2,
+                )
+            },
+            reference: <$solution as Solution>::reference_part2,
+            no_reference: <$solution as Solution>::NO_REFERENCE,
+            params: <$solution as Solution>::PARAMS,
+        }
+    };
+}
+
+pub const SOLUTIONS: &[Entry] = &[
+    solution!(day_1::Day1, 1),
+    solution!(day_1::Day1, 2),
+    solution!(day_2::Day2, 1),
+    solution!(day_2::Day2, 2),
+    solution!(day_3::Day3, 1),
+    solution!(day_3::Day3, 2),
+    solution!(day_4::Day4, 1),
+    solution!(day_4::Day4, 2),
+    solution!(day_5::Day5, 1),
+    solution!(day_5::Day5, 2),
+    solution!(day_6::Day6, 1),
+    solution!(day_6::Day6, 2),
+    solution!(day_7::Day7, 1),
+    solution!(day_7::Day7, 2),
+    solution!(day_8::Day8, 1),
+    solution!(day_8::Day8, 2),
+    solution!(day_9::Day9, 1),
+    solution!(day_9::Day9, 2),
+    solution!(day_10::Day10, 1),
+    solution!(day_10::Day10, 2),
+    solution!(day_11::Day11, 1),
+    solution!(day_11::Day11, 2),
+    solution!(day_12::Day12, 1),
+    solution!(day_12::Day12, 2),
+    solution!(day_13::Day13, 1),
+    solution!(day_13::Day13, 2),
+    solution!(day_14::Day14, 1),
+    solution!(day_14::Day14, 2),
+    solution!(day_15::Day15, 1),
+    solution!(day_15::Day15, 2),
+    solution!(day_16::Day16, 1),
+    solution!(day_16::Day16, 2),
+    solution!(day_17::Day17, 1),
+    solution!(day_17::Day17, 2),
+    solution!(day_18::Day18, 1),
+    solution!(day_18::Day18, 2),
+];
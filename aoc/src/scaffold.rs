@@ -0,0 +1,458 @@
+//! `aoc new <day>`: creates a day crate from a template and registers it everywhere a day is
+//! listed.
+
+use std::fs;
+use std::ops::Range;
+use std::path::Path;
+
+use aoc_common::{Error, Result};
+
+const LIB_TEMPLATE: &str = r#"use aoc_common::{Answer, Error, Override, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const DAY: u8 = {day};
+    const INPUT_PATH: &'static str = "day-{day}/input.txt";
+
+    type Input = Vec<String>;
+
+    fn parse_with(
+        input: &str,
+        _overrides: &[Override],
+    ) -> aoc_common::Result<Self::Input> {
+        Ok(aoc_common::parse_lines(input))
+    }
+
+    fn part1(_input: &Self::Input) -> aoc_common::Result<Answer> {
+        Err(Error::NoAnswer("part 1 is not solved yet".to_string()))
+    }
+
+    fn part2(_input: &Self::Input) -> aoc_common::Result<Answer> {
+        Err(Error::NoAnswer("part 2 is not solved yet".to_string()))
+    }
+}
+"#;
+
+const MAIN_TEMPLATE: &str = r#"use aoc_common::OrExit;
+
+fn main() {
+    aoc_common::run_day::<day_{day}::Day{day}>(["Part 1: ", "Part 2: "]).or_exit();
+}
+"#;
+
+const MANIFEST_TEMPLATE: &str = r#"[package]
+name = "day-{day}"
+version = "0.1.0"
+edition = "{edition}"
+
+[dependencies]
+aoc-common.workspace = true
+"#;
+
+/// The edition of a new day when neither a day crate nor the `aoc` manifest names one.
+const DEFAULT_EDITION: &str = "2021";
+
+/// What the template answers for the empty example, in the form of `test-inputs/answers.toml`.
+const ANSWERS_TEMPLATE: &str = r#"[day-{day}]
+part-1 = "error: No answer: part 1 is not solved yet"
+part-2 = "error: No answer: part 2 is not solved yet"
+"#;
+
+/// Creates `day-N/` with an empty `input.txt`, an empty example under `test-inputs/` and the
+/// expected answers of the template, then adds the day to the workspace members and to the
+/// solutions the `aoc` binary runs. Nothing is written when any of these already has the day.
+pub fn new_day(day: u8) -> Result<()> {
+    let crate_dir = aoc_common::input_path(format!("day-{}", day))?;
+    let example_path = aoc_common::input_path(format!("test-inputs/{}.txt", day))?;
+    let workspace_manifest = aoc_common::input_path("Cargo.toml")?;
+    let aoc_manifest = aoc_common::input_path("aoc/Cargo.toml")?;
+    let registry = aoc_common::input_path("aoc/src/registry.rs")?;
+    let answers = aoc_common::input_path("test-inputs/answers.toml")?;
+
+    let exists = |what: &str| Error::Usage(format!("day {} already exists: {}", day, what));
+    if crate_dir.exists() {
+        return Err(exists(&crate_dir.display().to_string()));
+    }
+    if example_path.exists() {
+        return Err(exists(&example_path.display().to_string()));
+    }
+
+    let workspace = aoc_common::read_file(&workspace_manifest)?;
+    let dependencies = aoc_common::read_file(&aoc_manifest)?;
+    let edition = match nearest_day(&workspace, day) {
+        Some(neighbour) => {
+            let manifest = aoc_common::input_path(format!("day-{}/Cargo.toml", neighbour))?;
+            edition_of(&aoc_common::read_file(&manifest)?).map(str::to_string)
+        }
+        None => edition_of(&dependencies).map(str::to_string),
+    }
+    .unwrap_or_else(|| DEFAULT_EDITION.to_string());
+    let fill = |template: &str| {
+        template
+            .replace("{day}", &day.to_string())
+            .replace("{edition}", &edition)
+    };
+
+    let updated_members =
+        with_member(&workspace, day).ok_or_else(|| exists("it is already a workspace member"))?;
+    let updated_dependencies =
+        with_dependency(&dependencies, day).ok_or_else(|| exists("aoc already depends on it"))?;
+    let updated_registry = with_solutions(&aoc_common::read_file(&registry)?, day)
+        .ok_or_else(|| exists("it is already registered"))?;
+    let updated_answers = with_answers(&aoc_common::read_file(&answers)?, day)
+        .ok_or_else(|| exists("it already has expected answers"))?;
+
+    fs::create_dir_all(crate_dir.join("src")).map_err(|source| Error::Write {
+        path: crate_dir.clone(),
+        source,
+    })?;
+    write(&crate_dir.join("Cargo.toml"), &fill(MANIFEST_TEMPLATE))?;
+    write(&crate_dir.join("src/lib.rs"), &fill(LIB_TEMPLATE))?;
+    write(&crate_dir.join("src/main.rs"), &fill(MAIN_TEMPLATE))?;
+    write(&crate_dir.join("input.txt"), "")?;
+    write(&example_path, "")?;
+    write(&workspace_manifest, &updated_members)?;
+    write(&aoc_manifest, &updated_dependencies)?;
+    write(&registry, &updated_registry)?;
+    write(&answers, &updated_answers)?;
+
+    Ok(())
+}
+
+/// The workspace manifest with `day-N` added to `members`, every member on its own line and the
+/// days sorted by number. `None` when it is already a member.
+fn with_member(manifest: &str, day: u8) -> Option<String> {
+    let Range { start, end } = members_list(manifest)?;
+
+    let mut members = manifest[start..end]
+        .split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    let new_member = format!("day-{}", day);
+    if members.contains(&new_member) {
+        return None;
+    }
+    members.push(new_member);
+    members.sort_by_key(|member| {
+        let day = member
+            .strip_prefix("day-")
+            .and_then(|day| day.parse::<u8>().ok());
+        (day, member.clone())
+    });
+
+    let listed = members
+        .iter()
+        .map(|member| format!("    \"{}\",\n", member))
+        .collect::<Vec<_>>()
+        .concat();
+    Some(format!(
+        "{}\n{}{}",
+        &manifest[..start],
+        listed,
+        &manifest[end..]
+    ))
+}
+
+/// Where the entries of `members` are in the workspace manifest, between the brackets.
+fn members_list(manifest: &str) -> Option<Range<usize>> {
+    let start = manifest.find("members = [")? + "members = [".len();
+    let end = start + manifest[start..].find(']')?;
+    Some(start..end)
+}
+
+/// The day among the workspace members closest to `day`, the earlier one of two as close, whose
+/// edition a new day takes. `None` when there is no day yet.
+fn nearest_day(manifest: &str, day: u8) -> Option<u8> {
+    manifest[members_list(manifest)?]
+        .split(',')
+        .filter_map(|member| {
+            let member = member.trim().trim_matches('"');
+            member.strip_prefix("day-")?.parse::<u8>().ok()
+        })
+        .min_by_key(|&other| (other.abs_diff(day), other > day))
+}
+
+/// The `edition` of a package manifest.
+fn edition_of(manifest: &str) -> Option<&str> {
+    manifest.lines().find_map(|line| {
+        let value = line
+            .trim()
+            .strip_prefix("edition")?
+            .trim_start()
+            .strip_prefix('=')?;
+        value.trim().strip_prefix('"')?.strip_suffix('"')
+    })
+}
+
+/// The `aoc` manifest with a dependency on `day-N`. `None` when it already has one.
+fn with_dependency(manifest: &str, day: u8) -> Option<String> {
+    insert_sorted(
+        manifest,
+        day,
+        &format!("day-{0} = {{ path = \"../day-{0}\" }}\n", day),
+        |line| line.strip_prefix("day-")?.split(' ').next()?.parse().ok(),
+        |line| line.starts_with("aoc-common"),
+    )
+}
+
+/// The registry with both parts of day N in `SOLUTIONS`. `None` when it already has the day.
+fn with_solutions(registry: &str, day: u8) -> Option<String> {
+    insert_sorted(
+        registry,
+        day,
+        &format!(
+            "    solution!(day_{0}::Day{0}, 1),\n    solution!(day_{0}::Day{0}, 2),\n",
+            day
+        ),
+        |line| {
+            let rest = line.trim_start().strip_prefix("solution!(day_")?;
+            rest.split(':').next()?.parse().ok()
+        },
+        |line| line.starts_with("pub const SOLUTIONS"),
+    )
+}
+
+/// `answers.toml` with the answers of the template for day N. `None` when it already has the day.
+fn with_answers(answers: &str, day: u8) -> Option<String> {
+    insert_sorted(
+        answers,
+        day,
+        &format!("{}\n", ANSWERS_TEMPLATE.replace("{day}", &day.to_string())),
+        |line| line.strip_prefix("[day-")?.strip_suffix(']')?.parse().ok(),
+        |_| false,
+    )
+}
+
+/// Inserts `text` before the first line that belongs to a later day, going by `day_of`, or else
+/// after the block of the last earlier day. Without any day it goes after the first line matching
+/// `is_start`, or at the end of the file. `None` when a line already belongs to `day`.
+fn insert_sorted(
+    file: &str,
+    day: u8,
+    text: &str,
+    day_of: impl Fn(&str) -> Option<u8>,
+    is_start: impl Fn(&str) -> bool,
+) -> Option<String> {
+    let lines = file.split_inclusive('\n').collect::<Vec<_>>();
+    let day_of = |line: &str| day_of(line.trim_end());
+    if lines.iter().any(|line| day_of(line) == Some(day)) {
+        return None;
+    }
+
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, day_of(line)?)))
+        .collect::<Vec<_>>();
+    let index = match days.iter().find(|&&(_, other)| other > day) {
+        Some(&(later, _)) => later,
+        None => match days.last() {
+            Some(&(last, _)) => end_of_block(&lines, last),
+            None => lines
+                .iter()
+                .position(|line| is_start(line))
+                .map_or(lines.len(), |start| start + 1),
+        },
+    };
+
+    let mut inserted = lines[..index].concat();
+    match text.strip_suffix("\n\n") {
+        // A block separated by a blank line, which goes before it at the end of the file.
+        Some(block) if index == lines.len() => {
+            if !inserted.is_empty() && !inserted.ends_with("\n\n") {
+                inserted.push('\n');
+            }
+            inserted.push_str(block);
+            inserted.push('\n');
+        }
+        _ => inserted.push_str(text),
+    }
+    inserted.push_str(&lines[index..].concat());
+    Some(inserted)
+}
+
+/// The index right after the block of the day starting at `index`. In `answers.toml` that is the
+/// header, its keys and the blank line after them, everywhere else a single line.
+fn end_of_block(lines: &[&str], index: usize) -> usize {
+    let mut end = index + 1;
+    if lines[index].starts_with('[') {
+        while end < lines.len() && !lines[end].trim().is_empty() {
+            end += 1;
+        }
+        if end < lines.len() {
+            end += 1;
+        }
+    }
+    end
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).map_err(|source| Error::Write {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORKSPACE: &str = "[workspace]
+members = [
+    \"aoc\",
+    \"day-1\",
+    \"day-3\",
+]
+
+[workspace.dependencies]
+";
+
+    const REGISTRY: &str = "pub const SOLUTIONS: &[Entry] = &[
+    solution!(day_1::Day1, 1),
+    solution!(day_1::Day1, 2),
+    solution!(day_3::Day3, 1),
+    solution!(day_3::Day3, 2),
+];
+";
+
+    const ANSWERS: &str = "# Expected answers.
+
+[day-1]
+part-1 = 11
+part-2 = 31
+
+[day-3]
+part-1 = 161
+part-2 = 48
+";
+
+    fn members(manifest: &str) -> Vec<&str> {
+        manifest
+            .lines()
+            .filter_map(|line| line.trim().strip_suffix("\",")?.strip_prefix('"'))
+            .collect()
+    }
+
+    fn answers_of(day: u8) -> String {
+        format!(
+            "[day-{0}]\npart-1 = \"error: No answer: part 1 is not solved yet\"\n\
+             part-2 = \"error: No answer: part 2 is not solved yet\"\n",
+            day
+        )
+    }
+
+    #[test]
+    fn members_get_the_day_in_order() {
+        let middle = with_member(WORKSPACE, 2).unwrap();
+        assert_eq!(members(&middle), ["aoc", "day-1", "day-2", "day-3"]);
+        assert!(middle.ends_with("]\n\n[workspace.dependencies]\n"));
+
+        let last = with_member(WORKSPACE, 12).unwrap();
+        assert_eq!(members(&last), ["aoc", "day-1", "day-3", "day-12"]);
+
+        let first = with_member("members = [\"aoc\", \"aoc-common\"]\n", 1).unwrap();
+        assert_eq!(
+            first,
+            "members = [\n    \"aoc\",\n    \"aoc-common\",\n    \"day-1\",\n]\n"
+        );
+    }
+
+    #[test]
+    fn unsorted_members_come_out_sorted() {
+        let manifest = "members = [\"day-10\", \"aoc\", \"day-2\"]\n";
+        assert_eq!(members(&with_member(manifest, 5).unwrap()), [
+            "aoc", "day-2", "day-5", "day-10"
+        ]);
+    }
+
+    #[test]
+    fn solutions_go_between_the_days_around_them() {
+        let solutions = |day| {
+            format!(
+                "    solution!(day_{0}::Day{0}, 1),\n    solution!(day_{0}::Day{0}, 2),\n",
+                day
+            )
+        };
+
+        let middle = with_solutions(REGISTRY, 2).unwrap();
+        assert_eq!(
+            middle,
+            REGISTRY.replace(
+                "    solution!(day_3::Day3, 1)",
+                &format!("{}    solution!(day_3::Day3, 1)", solutions(2))
+            )
+        );
+
+        let last = with_solutions(REGISTRY, 4).unwrap();
+        assert_eq!(
+            last,
+            REGISTRY.replace("];\n", &format!("{}];\n", solutions(4)))
+        );
+
+        let empty = "pub const SOLUTIONS: &[Entry] = &[\n];\n";
+        assert_eq!(
+            with_solutions(empty, 1).unwrap(),
+            format!("pub const SOLUTIONS: &[Entry] = &[\n{}];\n", solutions(1))
+        );
+    }
+
+    #[test]
+    fn dependencies_go_after_the_shared_crate_without_days() {
+        let manifest = "[dependencies]\naoc-common.workspace = true\nrayon = \"1.7\"\n";
+        assert_eq!(
+            with_dependency(manifest, 1).unwrap(),
+            "[dependencies]\naoc-common.workspace = true\nday-1 = { path = \"../day-1\" }\n\
+             rayon = \"1.7\"\n"
+        );
+
+        let with_day = with_dependency(manifest, 1).unwrap();
+        assert_eq!(
+            with_dependency(&with_day, 2).unwrap(),
+            with_day.replace("rayon", "day-2 = { path = \"../day-2\" }\nrayon")
+        );
+    }
+
+    #[test]
+    fn answer_blocks_keep_their_blank_line() {
+        let middle = with_answers(ANSWERS, 2).unwrap();
+        assert_eq!(
+            middle,
+            ANSWERS.replace("[day-3]", &format!("{}\n[day-3]", answers_of(2)))
+        );
+
+        let last = with_answers(ANSWERS, 4).unwrap();
+        assert_eq!(last, format!("{}\n{}", ANSWERS, answers_of(4)));
+
+        let first = with_answers("# Expected answers.\n", 1).unwrap();
+        assert_eq!(first, format!("# Expected answers.\n\n{}", answers_of(1)));
+    }
+
+    #[test]
+    fn a_day_is_only_added_once() {
+        assert_eq!(with_member(WORKSPACE, 3), None);
+        assert_eq!(with_solutions(REGISTRY, 1), None);
+        assert_eq!(with_answers(ANSWERS, 3), None);
+        let manifest = "aoc-common.workspace = true\nday-7 = { path = \"../day-7\" }\n";
+        assert_eq!(with_dependency(manifest, 7), None);
+    }
+
+    #[test]
+    fn a_new_day_takes_the_edition_of_its_nearest_neighbour() {
+        assert_eq!(nearest_day(WORKSPACE, 2), Some(1));
+        assert_eq!(nearest_day(WORKSPACE, 9), Some(3));
+        assert_eq!(
+            nearest_day("members = [\"day-4\", \"day-8\"]\n", 6),
+            Some(4)
+        );
+        assert_eq!(nearest_day("members = [\n    \"aoc\",\n]\n", 1), None);
+
+        assert_eq!(
+            edition_of("[package]\nname = \"day-1\"\nedition = \"2021\"\n"),
+            Some("2021")
+        );
+        assert_eq!(edition_of("[package]\nname = \"day-1\"\n"), None);
+    }
+}
@@ -0,0 +1,83 @@
+//! `aoc check` on made-up solutions, to see what it counts as a disagreement.
+
+use aoc::check::{self, Outcome};
+use aoc::generate::Generator;
+use aoc::registry::{self, Entry};
+use aoc_common::{Answer, Override, ParseError, Rng};
+
+const GENERATOR: Generator = Generator {
+    day: 1,
+    size: "numbers",
+    default_size: 10,
+    check_size: 10,
+    generate: numbers,
+};
+
+fn numbers(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| rng.below(100).to_string() + "\n")
+        .collect()
+}
+
+fn rejected(input: &str, _overrides: &[Override]) -> aoc_common::Result<Answer> {
+    Err(ParseError::invalid(input, &input[..0], "rejected").into())
+}
+
+fn count(input: &str, _overrides: &[Override]) -> aoc_common::Result<Answer> {
+    Ok(input.lines().count().into())
+}
+
+fn entry(
+    solve: fn(&str, &[Override]) -> aoc_common::Result<Answer>,
+    reference: fn(&str) -> Option<aoc_common::Result<Answer>>,
+) -> Entry {
+    Entry {
+        solve,
+        reference,
+        ..registry::SOLUTIONS[0]
+    }
+}
+
+fn outcome(part: &Entry) -> Outcome {
+    check::check(part, &GENERATOR, 20, GENERATOR.check_size, 1)
+}
+
+#[test]
+fn inputs_that_neither_side_parses_agree() {
+    let part = entry(rejected, |input| Some(rejected(input, &[])));
+    assert!(matches!(outcome(&part), Outcome::Agreed { inputs: 20 }));
+}
+
+#[test]
+fn inputs_that_only_one_side_parses_disagree() {
+    let cases = [
+        (entry(rejected, |input| Some(count(input, &[]))), true),
+        (entry(count, |input| Some(rejected(input, &[]))), false),
+    ];
+
+    for (part, solution_rejects) in cases {
+        let Outcome::Mismatch(mismatch) = outcome(&part) else {
+            panic!("agrees when only one side parses");
+        };
+        let (unparsable, answered) = match solution_rejects {
+            true => (mismatch.answer, mismatch.reference),
+            false => (mismatch.reference, mismatch.answer),
+        };
+        assert_eq!(unparsable, "unparsable");
+        assert!(answered.parse::<usize>().is_ok(), "{:?}", answered);
+    }
+}
+
+#[test]
+fn every_part_with_a_reference_can_be_checked() {
+    for part in registry::SOLUTIONS {
+        if (part.reference)("").is_some() {
+            assert!(
+                aoc::generate::generator(part.day).is_some(),
+                "day {} part {} has a reference but no generator",
+                part.day,
+                part.part
+            );
+        }
+    }
+}
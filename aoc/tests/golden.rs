@@ -0,0 +1,119 @@
+//! Runs every day and part on its example input under `test-inputs/` and compares the answer
+//! with the one recorded in `test-inputs/answers.toml`.
+
+use aoc::answers::{self, ANSWERS_PATH, Answers};
+use aoc::registry::{Entry, SOLUTIONS};
+use aoc_common::{Answer, InputSource};
+
+fn read_answers() -> Answers {
+    answers::read_answers().unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// What `aoc run <day> <part> --example` shows in the Answer column.
+fn run_example(solution: &Entry) -> String {
+    let answer = InputSource::Example
+        .read(solution.day, solution.part, solution.input_path)
+        .and_then(|input| input.solve(|text| (solution.solve)(text, &[])));
+
+    shown(answer)
+}
+
+/// Like [`run_example`], with the slow reference solution. `None` when the part has none.
+fn run_reference(solution: &Entry) -> Option<String> {
+    let input = InputSource::Example
+        .read(solution.day, solution.part, solution.input_path)
+        .unwrap_or_else(|error| panic!("{}", error));
+    let answer = (solution.reference)(&input.text)?;
+
+    Some(shown(answer.map_err(|error| error.in_file(&input.name))))
+}
+
+fn shown(answer: aoc_common::Result<Answer>) -> String {
+    match answer {
+        Ok(answer) => answer.to_string(),
+        Err(error) => format!("error: {}", error),
+    }
+}
+
+#[test]
+fn every_solution_has_a_recorded_answer() {
+    let answers = read_answers();
+
+    let missing = SOLUTIONS
+        .iter()
+        .filter(|solution| !answers.contains_key(&(solution.day, solution.part)))
+        .map(|solution| format!("day {} part {}", solution.day, solution.part))
+        .collect::<Vec<_>>();
+    assert!(
+        missing.is_empty(),
+        "no answer recorded in {} for {}",
+        ANSWERS_PATH,
+        missing.join(", ")
+    );
+
+    let unknown = answers
+        .keys()
+        .filter(|&&(day, part)| {
+            !SOLUTIONS
+                .iter()
+                .any(|solution| (solution.day, solution.part) == (day, part))
+        })
+        .map(|(day, part)| format!("day {} part {}", day, part))
+        .collect::<Vec<_>>();
+    assert!(
+        unknown.is_empty(),
+        "{} records answers for missing solutions: {}",
+        ANSWERS_PATH,
+        unknown.join(", ")
+    );
+}
+
+#[test]
+fn examples_give_the_recorded_answers() {
+    let answers = read_answers();
+
+    let failures = SOLUTIONS
+        .iter()
+        .filter_map(|solution| {
+            let expected = answers.get(&(solution.day, solution.part))?;
+            let actual = run_example(solution);
+            (&actual != expected).then(|| {
+                format!(
+                    "day {} part {}: expected {:?}, got {:?}",
+                    solution.day, solution.part, expected, actual
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    assert!(
+        failures.is_empty(),
+        "wrong answers for the examples:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn references_give_the_recorded_answers() {
+    let answers = read_answers();
+
+    let failures = SOLUTIONS
+        .iter()
+        .filter_map(|solution| {
+            let expected = answers.get(&(solution.day, solution.part))?;
+            let actual = run_reference(solution)?;
+            (&actual != expected).then(|| {
+                format!(
+                    "day {} part {}: expected {:?}, got {:?}",
+                    solution.day, solution.part, expected, actual
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    assert!(
+        failures.is_empty(),
+        "wrong reference answers for the examples:\n{}",
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,89 @@
+//! Reading and writing the answer ledger, and comparing reports with it.
+
+use std::time::Duration;
+
+use aoc_common::ledger::{Ledger, Status};
+use aoc_common::{Answer, Input, Report};
+
+fn report(day: u8, part: u8, text: &str, answer: Result<Answer, String>) -> Report {
+    let input = Input {
+        name: "input.txt".to_string(),
+        text: text.to_string(),
+    };
+    Report::new(day, part, &input, answer, Duration::ZERO)
+}
+
+fn sha256(report: &Report) -> &str {
+    report.input_sha256.as_deref().unwrap()
+}
+
+#[test]
+fn ledger_round_trips_through_json() {
+    let mut ledger = Ledger::new();
+    ledger.accept(1, 1, "ab12", "11".to_string());
+    ledger.accept(1, 2, "ab12", "31".to_string());
+    ledger.accept(17, 1, "cd34", "4,6,3,5,6,3,5,2,1,0".to_string());
+    ledger.accept(25, 2, "ef56", "a \"quoted\" \\ answer\n".to_string());
+
+    assert_eq!(Ledger::parse(&ledger.to_json()), Ok(ledger));
+    assert_eq!(Ledger::parse(&Ledger::new().to_json()), Ok(Ledger::new()));
+}
+
+#[test]
+fn ledger_accepts_keys_in_any_order() {
+    let ledger =
+        Ledger::parse(r#" [ { "answer" : "7", "part" : 2, "input_sha256" : "ab", "day" : 3 } ] "#)
+            .unwrap();
+
+    assert_eq!(ledger.get(3, 2, "ab"), Some("7"));
+    assert_eq!(ledger.get(3, 1, "ab"), None);
+}
+
+#[test]
+fn ledger_rejects_malformed_files() {
+    let malformed = [
+        "",
+        "{}",
+        "[",
+        r#"[{"day":1,"part":1,"input_sha256":"ab"}]"#,
+        r#"[{"day":1,"part":1,"input_sha256":"ab","answer":7}]"#,
+        r#"[{"day":1,"part":1,"input_sha256":"ab","answer":"7","extra":"x"}]"#,
+        r#"[{"day":1,"part":1,"input_sha256":"ab","answer":"7"},]"#,
+        r#"[{"day":300,"part":1,"input_sha256":"ab","answer":"7"}]"#,
+        r#"[{"day":1,"part":1,"input_sha256":"ab","answer":"7\q"}]"#,
+        r#"[{"day":1,"part":1,"input_sha256":"ab","answer":"7}]"#,
+        r#"[{"day":1,"part":1,"input_sha256":"ab","answer":"7"}] []"#,
+        r#"[{"day":1,"part":1,"input_sha256":"ab","answer":"7"},
+            {"day":1,"part":1,"input_sha256":"ab","answer":"8"}]"#,
+    ];
+
+    for text in malformed {
+        assert!(Ledger::parse(text).is_err(), "{:?} was accepted", text);
+    }
+}
+
+#[test]
+fn reports_are_compared_with_the_accepted_answer() {
+    let right = report(1, 1, "3 4\n", Ok(Answer::from(11)));
+    let wrong = report(1, 1, "3 4\n", Ok(Answer::from(12)));
+    let error = report(1, 1, "3 4\n", Err("no answer".to_string()));
+    let other_input = report(1, 1, "4 3\n", Ok(Answer::from(11)));
+    let other_part = report(1, 2, "3 4\n", Ok(Answer::from(11)));
+    let unread = Report::unread(1, 1, "cannot read".to_string());
+
+    let mut ledger = Ledger::new();
+    assert_eq!(ledger.accept(1, 1, sha256(&right), "11".to_string()), None);
+
+    assert_eq!(ledger.status(&right), Some(Status::Matches));
+    assert_eq!(ledger.status(&wrong), Some(Status::Differs));
+    assert_eq!(ledger.status(&error), Some(Status::Differs));
+    assert_eq!(ledger.status(&other_input), Some(Status::Unknown));
+    assert_eq!(ledger.status(&other_part), Some(Status::Unknown));
+    assert_eq!(ledger.status(&unread), None);
+
+    assert_eq!(
+        ledger.accept(1, 1, sha256(&right), "12".to_string()),
+        Some("11".to_string())
+    );
+    assert_eq!(ledger.status(&wrong), Some(Status::Matches));
+}
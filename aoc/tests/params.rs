@@ -0,0 +1,101 @@
+//! `--param` on the command line, and checking the names it sets against the parameters of the
+//! days.
+
+use aoc::registry::SOLUTIONS;
+use aoc_common::{Error, Param, params};
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+/// The parameters of `day`, as `aoc run <day>` checks `--param` against them.
+fn params_of(day: u8) -> &'static [Param] {
+    SOLUTIONS
+        .iter()
+        .find(|solution| solution.day == day)
+        .map(|solution| solution.params)
+        .unwrap()
+}
+
+#[test]
+fn params_are_taken_out_of_the_arguments() {
+    let mut arguments = args(&["run", "--param", "part2_blinks=6", "11", "--param", "a=1=2"]);
+    let overrides = params::extract(&mut arguments).unwrap();
+
+    assert_eq!(arguments, args(&["run", "11"]));
+    assert_eq!(overrides, [
+        ("part2_blinks".to_string(), "6".to_string()),
+        ("a".to_string(), "1=2".to_string())
+    ]);
+}
+
+#[test]
+fn malformed_params_are_usage_errors() {
+    for arguments in [
+        &["run", "11", "--param"][..],
+        &["run", "11", "--param", "part2_blinks"],
+        &["--param", "a=1", "--param", "a=2"],
+    ] {
+        assert!(
+            matches!(params::extract(&mut args(arguments)), Err(Error::Usage(_))),
+            "{:?}",
+            arguments
+        );
+    }
+}
+
+#[test]
+fn overrides_have_to_be_parameters_of_the_day() {
+    let overrides = |name: &str| vec![(name.to_string(), "6".to_string())];
+
+    assert!(params::check_overrides(&overrides("part2_blinks"), params_of(11), "day 11").is_ok());
+    assert!(params::check_overrides(&[], params_of(4), "day 4").is_ok());
+
+    let Err(Error::Usage(message)) =
+        params::check_overrides(&overrides("part2_blink"), params_of(11), "day 11")
+    else {
+        panic!("a misspelt parameter is accepted");
+    };
+    assert_eq!(
+        message,
+        "\"part2_blink\" is not a parameter of day 11, the parameters are part1_blinks, \
+         part2_blinks"
+    );
+    let Err(Error::Usage(message)) =
+        params::check_overrides(&overrides("part2_blinks"), params_of(4), "day 4")
+    else {
+        panic!("a parameter of another day is accepted");
+    };
+    assert!(message.ends_with("there are none"), "{}", message);
+}
+
+#[test]
+fn every_run_solves_with_its_own_overrides() {
+    let part1 = SOLUTIONS
+        .iter()
+        .find(|solution| solution.day == 11 && solution.part == 1)
+        .unwrap();
+    let blinks = |value: &str| vec![("part1_blinks".to_string(), value.to_string())];
+
+    // The example's stones after 6 and after 25 blinks, and again after 6.
+    assert_eq!(
+        (part1.solve)("125 17\n", &blinks("6")).unwrap().to_string(),
+        "22"
+    );
+    assert_eq!(
+        (part1.solve)("125 17\n", &blinks("25"))
+            .unwrap()
+            .to_string(),
+        "55312"
+    );
+    assert_eq!(
+        (part1.solve)("125 17\n", &blinks("6")).unwrap().to_string(),
+        "22"
+    );
+    assert_eq!(
+        (part1.solve)("part1_blinks=6\n125 17\n", &[])
+            .unwrap()
+            .to_string(),
+        "22"
+    );
+}
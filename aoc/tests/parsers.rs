@@ -0,0 +1,554 @@
+//! Property tests for the input parsers of every day, on the random inputs of [`aoc::generate`].
+//! A generated input has to parse into values that render back into the same text, and an input
+//! corrupted in a way the puzzle format does not allow has to be a parse error.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+
+use aoc::generate;
+use aoc_common::{Error, Rng, Solution};
+
+/// How many seeds every property is checked with.
+const CASES: u64 = 100;
+
+/// Runs `property` with a generator seeded with each of `0..CASES`, naming the seed it fails
+/// with.
+fn for_each_seed(property: impl Fn(&mut Rng)) {
+    for seed in 0..CASES {
+        if panic::catch_unwind(AssertUnwindSafe(|| property(&mut Rng::new(seed)))).is_err() {
+            panic!("the property does not hold with seed {}", seed);
+        }
+    }
+}
+
+/// Every input from `generate` parses, and `render` turns the parsed values back into it.
+fn round_trips<S: Solution>(
+    generate: impl Fn(&mut Rng) -> String,
+    render: impl Fn(&S::Input) -> String,
+) {
+    for_each_seed(|rng| {
+        let text = generate(rng);
+        let parsed =
+            S::parse(&text).unwrap_or_else(|error| panic!("{:?} does not parse: {}", text, error));
+        assert_eq!(render(&parsed), text);
+    });
+}
+
+/// Every input from `generate` is a parse error after `corrupt`.
+fn rejects<S: Solution>(
+    generate: impl Fn(&mut Rng) -> String,
+    corrupt: impl Fn(&mut Rng, String) -> String,
+) {
+    for_each_seed(|rng| {
+        let text = generate(rng);
+        let corrupted = corrupt(rng, text);
+        match S::parse(&corrupted) {
+            Err(Error::Parse(_)) => {}
+            Err(error) => panic!("{:?} gives a {:?} error", corrupted, error),
+            Ok(_) => panic!("{:?} parses", corrupted),
+        }
+    });
+}
+
+/// Parsing never panics on an input from `generate` with a few characters deleted, duplicated
+/// or swapped, whether or not the result is still valid.
+fn never_panics<S: Solution>(generate: impl Fn(&mut Rng) -> String) {
+    for_each_seed(|rng| {
+        let mut text = generate(rng).into_bytes();
+        for _ in 0..rng.between(1, 3) {
+            if text.is_empty() {
+                break;
+            }
+            let (at, other) = (rng.index(text.len()), rng.index(text.len()));
+            match rng.below(3) {
+                0 => {
+                    text.remove(at);
+                }
+                1 => text.insert(at, text[other]),
+                _ => text.swap(at, other),
+            }
+        }
+        let _ = S::parse(&String::from_utf8(text).expect("the inputs are ASCII"));
+    });
+}
+
+/// Calls `generate` with a size from 4 to 20, enough for a grid to have room for a second unique
+/// tile and small enough to read when a property fails.
+fn sized(generate: fn(&mut Rng, usize) -> String) -> impl Fn(&mut Rng) -> String {
+    move |rng| {
+        let size = rng.between(4, 20) as usize;
+        generate(rng, size)
+    }
+}
+
+/// Inserts a letter, which only day 3 and the days of arbitrary grids allow anywhere.
+fn insert_letter(rng: &mut Rng, mut text: String) -> String {
+    text.insert(rng.index(text.len() + 1), 'x');
+    text
+}
+
+/// Removes a character from one row of a grid, so that the rows differ in length.
+fn shorten_row(rng: &mut Rng, text: String) -> String {
+    let mut rows = text.lines().map(str::to_string).collect::<Vec<_>>();
+    let row = rng.index(rows.len());
+    let column = rng.index(rows[row].len());
+    rows[row].remove(column);
+    rows.join("\n") + "\n"
+}
+
+/// Puts `tile` on the first position inside the outer wall of a grid that has none of the unique
+/// tiles.
+fn add_inside(text: &str, tile: char) -> String {
+    let mut rows = text
+        .lines()
+        .map(|row| row.chars().collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let height = rows.len();
+    let cell = rows[1..height - 1]
+        .iter_mut()
+        .flat_map(|row| {
+            let width = row.len();
+            &mut row[1..width - 1]
+        })
+        .find(|cell| ".#O".contains(**cell))
+        .expect("the grid has more positions than unique tiles");
+    *cell = tile;
+
+    rows.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+fn joined(numbers: impl IntoIterator<Item = impl ToString>, separator: &str) -> String {
+    numbers
+        .into_iter()
+        .map(|number| number.to_string())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+#[test]
+fn day_1_round_trips() {
+    round_trips::<day_1::Day1>(sized(generate::location_lists), |(lists, _)| {
+        let mut text = String::new();
+        for row in 0..lists.first().map_or(0, Vec::len) {
+            let ids = lists.iter().map(|list| list[row]).collect::<Vec<_>>();
+            let _ = writeln!(text, "{}", joined(ids, "   "));
+        }
+        text
+    });
+}
+
+#[test]
+fn day_1_rejects_malformed_input() {
+    rejects::<day_1::Day1>(sized(generate::location_lists), insert_letter);
+    // A line with another number of IDs than the others.
+    rejects::<day_1::Day1>(sized(generate::location_lists), |rng, text| {
+        format!("{}1 2 {}\n3 4\n", text, rng.below(10))
+    });
+}
+
+#[test]
+fn day_2_round_trips() {
+    round_trips::<day_2::Day2>(sized(generate::reactor_reports), |(reports, _)| {
+        reports
+            .iter()
+            .map(|levels| joined(levels, " ") + "\n")
+            .collect()
+    });
+}
+
+#[test]
+fn day_2_rejects_malformed_input() {
+    rejects::<day_2::Day2>(sized(generate::reactor_reports), insert_letter);
+    // One past the largest level.
+    rejects::<day_2::Day2>(sized(generate::reactor_reports), |_, text| {
+        format!("{}1 2147483648\n", text)
+    });
+}
+
+#[test]
+fn day_3_finds_every_mul() {
+    for_each_seed(|rng| {
+        let size = rng.between(4, 20) as usize;
+        let (text, muls) = generate::corrupted_memory_with_muls(rng, size);
+        let tokens = day_3::Day3::parse(&text).expect("corrupted memory always parses");
+        let parsed = tokens
+            .iter()
+            .filter(|token| token.instruction.name == "mul")
+            .map(|token| (token.offset, (token.operands[0], token.operands[1])))
+            .collect::<Vec<_>>();
+        assert_eq!(parsed, muls);
+    });
+}
+
+#[test]
+fn day_3_rejects_numbers_that_do_not_fit() {
+    // Operands of any length, as the puzzle's 3 digits always fit.
+    rejects::<day_3::Day3>(sized(generate::corrupted_memory), |rng, mut text| {
+        text.insert_str(rng.index(text.len() + 1), "mul(2147483648,1)");
+        format!("operand_digits=1..\n{}", text)
+    });
+}
+
+#[test]
+fn day_4_round_trips() {
+    round_trips::<day_4::Day4>(sized(generate::word_search), |grid| format!("{}\n", grid));
+}
+
+#[test]
+fn day_4_rejects_ragged_rows() {
+    rejects::<day_4::Day4>(sized(generate::word_search), shorten_row);
+}
+
+/// The rules of a [`generate::page_ordering`] input in the order the parsed rules render in.
+fn page_ordering(rng: &mut Rng) -> String {
+    let text = sized(generate::page_ordering)(rng);
+    let (rules, updates) = text.split_once("\n\n").expect("rules, then updates");
+    let rules = rules
+        .lines()
+        .map(|rule| {
+            let (before, after) = rule.split_once('|').expect("two pages");
+            (
+                before.parse::<i64>().unwrap(),
+                after.parse::<i64>().unwrap(),
+            )
+        })
+        .collect::<BTreeSet<_>>();
+
+    let mut sorted = String::new();
+    for (before, after) in rules {
+        let _ = writeln!(sorted, "{}|{}", before, after);
+    }
+    sorted + "\n" + updates
+}
+
+#[test]
+fn day_5_round_trips() {
+    round_trips::<day_5::Day5>(page_ordering, |(rules, updates)| {
+        let rules = rules
+            .iter()
+            .flat_map(|(&before, afters)| afters.iter().map(move |&after| (before, after)))
+            .collect::<BTreeSet<_>>();
+
+        let mut text = String::new();
+        for (before, after) in rules {
+            let _ = writeln!(text, "{}|{}", before, after);
+        }
+        text.push('\n');
+        for update in updates {
+            let _ = writeln!(text, "{}", joined(update, ","));
+        }
+        text
+    });
+}
+
+#[test]
+fn day_5_rejects_malformed_input() {
+    rejects::<day_5::Day5>(page_ordering, insert_letter);
+    // A rule of three pages.
+    rejects::<day_5::Day5>(page_ordering, |_, text| format!("1|2|3\n{}", text));
+}
+
+#[test]
+fn day_6_round_trips() {
+    round_trips::<day_6::Day6>(sized(generate::guard_map), |map| format!("{}\n", map));
+}
+
+#[test]
+fn day_6_rejects_malformed_input() {
+    rejects::<day_6::Day6>(sized(generate::guard_map), insert_letter);
+    // A second guard.
+    rejects::<day_6::Day6>(sized(generate::guard_map), |_, text| {
+        text.replacen(['.', '#'], "^", 1)
+    });
+}
+
+#[test]
+fn day_7_round_trips() {
+    round_trips::<day_7::Day7>(sized(generate::calibration_equations), |equations| {
+        let mut text = String::new();
+        for (test_value, values) in equations {
+            let _ = writeln!(text, "{}: {}", test_value, joined(values, " "));
+        }
+        text
+    });
+}
+
+#[test]
+fn day_7_rejects_malformed_input() {
+    let equations = sized(generate::calibration_equations);
+    rejects::<day_7::Day7>(&equations, insert_letter);
+    // No numbers after the test value, and a negative number.
+    rejects::<day_7::Day7>(&equations, |_, text| format!("{}190:\n", text));
+    rejects::<day_7::Day7>(&equations, |_, text| format!("{}190: 10 -19\n", text));
+}
+
+#[test]
+fn day_8_round_trips() {
+    round_trips::<day_8::Day8>(sized(generate::antenna_map), |grid| format!("{}\n", grid));
+}
+
+#[test]
+fn day_8_rejects_ragged_rows() {
+    rejects::<day_8::Day8>(sized(generate::antenna_map), shorten_row);
+}
+
+#[test]
+fn day_9_round_trips() {
+    round_trips::<day_9::Day9>(sized(generate::disk_map), |disk| {
+        let blocks = (0..disk.len())
+            .map(|index| disk[&index])
+            .collect::<Vec<_>>();
+
+        // A run of blocks of one file or of free space is one digit, with a 0 between two files
+        // next to each other.
+        let mut text = String::new();
+        for run in blocks.chunk_by(|block, next| block == next) {
+            let after_file = text.len() % 2 == 1;
+            if run[0] >= 0 && after_file {
+                text.push('0');
+            }
+            text.push_str(&run.len().to_string());
+        }
+        text + "\n"
+    });
+}
+
+#[test]
+fn day_9_rejects_malformed_input() {
+    rejects::<day_9::Day9>(sized(generate::disk_map), insert_letter);
+}
+
+#[test]
+fn day_10_round_trips() {
+    round_trips::<day_10::Day10>(sized(generate::topographic_map), |map| format!("{}\n", map));
+}
+
+#[test]
+fn day_10_rejects_malformed_input() {
+    rejects::<day_10::Day10>(sized(generate::topographic_map), insert_letter);
+}
+
+#[test]
+fn day_11_round_trips() {
+    round_trips::<day_11::Day11>(sized(generate::stones), |(stones, params)| {
+        format!(
+            "part2_blinks={}\n{}\n",
+            params.part2_blinks,
+            joined(stones, " ")
+        )
+    });
+}
+
+#[test]
+fn day_11_rejects_malformed_input() {
+    rejects::<day_11::Day11>(sized(generate::stones), insert_letter);
+    // A negative number.
+    rejects::<day_11::Day11>(sized(generate::stones), |_, text| format!("-1 {}", text));
+}
+
+#[test]
+fn day_12_round_trips() {
+    round_trips::<day_12::Day12>(sized(generate::garden_plots), |grid| format!("{}\n", grid));
+}
+
+#[test]
+fn day_12_rejects_ragged_rows() {
+    rejects::<day_12::Day12>(sized(generate::garden_plots), shorten_row);
+}
+
+fn render_machine(text: &mut String, [a_x, a_y, b_x, b_y, prize_x, prize_y]: [u64; 6]) {
+    let _ = writeln!(text, "Button A: X+{}, Y+{}", a_x, a_y);
+    let _ = writeln!(text, "Button B: X+{}, Y+{}", b_x, b_y);
+    let _ = writeln!(text, "Prize: X={}, Y={}", prize_x, prize_y);
+}
+
+#[test]
+fn day_13_round_trips() {
+    round_trips::<day_13::Day13>(sized(generate::claw_machines), |(machines, params)| {
+        let mut text = format!("prize_offset={}\n", params.prize_offset);
+        for (index, &(a_x, a_y, b_x, b_y, prize_x, prize_y)) in machines.iter().enumerate() {
+            if index > 0 {
+                text.push('\n');
+            }
+            render_machine(&mut text, [a_x, a_y, b_x, b_y, prize_x, prize_y]);
+        }
+        text
+    });
+}
+
+#[test]
+fn day_13_rejects_malformed_input() {
+    let machines = sized(generate::claw_machines);
+    rejects::<day_13::Day13>(&machines, insert_letter);
+    // A button that does not move the claw, and a machine without a prize.
+    rejects::<day_13::Day13>(&machines, |_, text| {
+        text + "\nButton A: X+0, Y+1\nButton B: X+1, Y+1\nPrize: X=1, Y=1\n"
+    });
+    rejects::<day_13::Day13>(&machines, |_, text| {
+        text + "\nButton A: X+1, Y+1\nButton B: X+1, Y+1\n"
+    });
+}
+
+/// A [`generate::robots`] input that sets the size of the area and the tree's columns and rows in
+/// its header even where they are the puzzle's, as the test renders them.
+fn robots(rng: &mut Rng) -> String {
+    let text = sized(generate::robots)(rng);
+    match text.starts_with("robot_area_size=") {
+        true => text,
+        false => format!(
+            "robot_area_size=101,103\ntree_columns=45..=79\ntree_rows=45..\n{}",
+            text
+        ),
+    }
+}
+
+/// A range of the tree as the generator writes it, without an end when it has none.
+fn tree_range(range: &RangeInclusive<usize>) -> String {
+    match *range.end() {
+        usize::MAX => format!("{}..", range.start()),
+        end => format!("{}..={}", range.start(), end),
+    }
+}
+
+#[test]
+fn day_14_round_trips() {
+    round_trips::<day_14::Day14>(robots, |(area, params)| {
+        let area = area.to_string();
+        let (size, robots) = area
+            .split_once('\n')
+            .expect("the size is on the first line");
+        format!(
+            "{}\ntree_columns={}\ntree_rows={}\ntree_robots={}\n{}",
+            size,
+            tree_range(&params.tree_columns),
+            tree_range(&params.tree_rows),
+            params.tree_robots,
+            robots
+        )
+    });
+}
+
+#[test]
+fn day_14_rejects_malformed_input() {
+    rejects::<day_14::Day14>(robots, insert_letter);
+    // An even width, and the size after the robots instead of at the top.
+    rejects::<day_14::Day14>(robots, |_, text| text.replacen(',', "0,", 1));
+    rejects::<day_14::Day14>(robots, |_, text| {
+        let (size, robots) = text
+            .split_once('\n')
+            .expect("the size is on the first line");
+        format!("p=0,0 v=0,0\n{}{}\n", robots, size)
+    });
+}
+
+#[test]
+fn day_15_round_trips() {
+    round_trips::<day_15::Day15>(sized(generate::warehouse), |(map, moves, _)| {
+        // In lines of 70, like the generator.
+        let mut text = format!("{}\n\n", map);
+        for line in moves.chunks(70) {
+            text.extend(line.iter().map(|direction| direction.arrow()));
+            text.push('\n');
+        }
+        text
+    });
+}
+
+#[test]
+fn day_15_rejects_malformed_input() {
+    rejects::<day_15::Day15>(sized(generate::warehouse), insert_letter);
+    // A second robot.
+    rejects::<day_15::Day15>(sized(generate::warehouse), |_, text| add_inside(&text, '@'));
+}
+
+#[test]
+fn day_16_round_trips() {
+    round_trips::<day_16::Day16>(sized(generate::reindeer_maze), |(maze, _, _)| {
+        format!("{}\n", maze)
+    });
+}
+
+#[test]
+fn day_16_rejects_malformed_input() {
+    let maze = sized(generate::reindeer_maze);
+    rejects::<day_16::Day16>(&maze, insert_letter);
+    // A second end, and no start.
+    rejects::<day_16::Day16>(&maze, |_, text| add_inside(&text, 'E'));
+    rejects::<day_16::Day16>(&maze, |_, text| text.replace('S', "."));
+}
+
+#[test]
+fn day_17_round_trips() {
+    round_trips::<day_17::Day17>(
+        sized(generate::three_bit_program),
+        |((a, b, c, program), _)| {
+            format!(
+                "Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {}\n",
+                a,
+                b,
+                c,
+                joined(program, ",")
+            )
+        },
+    );
+}
+
+#[test]
+fn day_17_rejects_malformed_input() {
+    let program = sized(generate::three_bit_program);
+    rejects::<day_17::Day17>(&program, insert_letter);
+    // A number that does not fit in 3 bits, and the reserved combo operand.
+    rejects::<day_17::Day17>(&program, |_, text| text.replace("Program: ", "Program: 8,"));
+    rejects::<day_17::Day17>(&program, |_, text| {
+        text.replace("Program: ", "Program: 0,7,")
+    });
+}
+
+#[test]
+fn day_18_round_trips() {
+    round_trips::<day_18::Day18>(sized(generate::falling_bytes), |(bytes, params)| {
+        let mut text = format!(
+            "memory_size={}\nfallen_bytes={}\n",
+            params.memory_size, params.fallen_bytes
+        );
+        for byte in bytes {
+            let _ = writeln!(text, "{}", byte);
+        }
+        text
+    });
+}
+
+#[test]
+fn day_18_rejects_malformed_input() {
+    rejects::<day_18::Day18>(sized(generate::falling_bytes), insert_letter);
+    // A byte outside the memory space.
+    rejects::<day_18::Day18>(sized(generate::falling_bytes), |_, text| {
+        format!("{}71,0\n", text)
+    });
+}
+
+#[test]
+fn parsers_never_panic() {
+    never_panics::<day_1::Day1>(sized(generate::location_lists));
+    never_panics::<day_2::Day2>(sized(generate::reactor_reports));
+    never_panics::<day_3::Day3>(sized(generate::corrupted_memory));
+    never_panics::<day_4::Day4>(sized(generate::word_search));
+    never_panics::<day_5::Day5>(sized(generate::page_ordering));
+    never_panics::<day_6::Day6>(sized(generate::guard_map));
+    never_panics::<day_7::Day7>(sized(generate::calibration_equations));
+    never_panics::<day_8::Day8>(sized(generate::antenna_map));
+    never_panics::<day_9::Day9>(sized(generate::disk_map));
+    never_panics::<day_10::Day10>(sized(generate::topographic_map));
+    never_panics::<day_11::Day11>(sized(generate::stones));
+    never_panics::<day_12::Day12>(sized(generate::garden_plots));
+    never_panics::<day_13::Day13>(sized(generate::claw_machines));
+    never_panics::<day_14::Day14>(sized(generate::robots));
+    never_panics::<day_15::Day15>(sized(generate::warehouse));
+    never_panics::<day_16::Day16>(sized(generate::reindeer_maze));
+    never_panics::<day_17::Day17>(sized(generate::three_bit_program));
+    never_panics::<day_18::Day18>(sized(generate::falling_bytes));
+}
@@ -0,0 +1,65 @@
+//! Runs every day on an input of the real size in the build `aoc run --all` uses by default,
+//! without the `parallel` feature, so that no day is too slow to finish there. It times the
+//! solutions, so it only runs in a release build:
+//!
+//! ```text
+//! cargo test --release -p aoc --test smoke
+//! ```
+
+#![cfg(not(feature = "parallel"))]
+
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use aoc::generate;
+use aoc::registry::{Entry, SOLUTIONS};
+use aoc_common::{Error, InputSource, Rng};
+
+/// How long a part may take, far more than any of them needs.
+const BUDGET: Duration = Duration::from_secs(60);
+
+/// The name and text of the real input when there is one, else of a generated input of about its
+/// size.
+fn real_size_input(solution: &Entry) -> (String, String) {
+    if let Ok(input) = InputSource::Default.read(solution.day, solution.part, solution.input_path) {
+        return (input.name, input.text);
+    }
+
+    let generator = generate::generator(solution.day)
+        .unwrap_or_else(|| panic!("day {} has neither an input nor a generator", solution.day));
+    let text = (generator.generate)(&mut Rng::new(2024), generator.default_size);
+    (format!("a generated input of day {}", solution.day), text)
+}
+
+#[test]
+#[cfg_attr(
+    debug_assertions,
+    ignore = "times the solutions, run it with --release"
+)]
+fn every_day_finishes_without_parallel() {
+    for solution in SOLUTIONS {
+        let (name, text) = real_size_input(solution);
+        let solve = solution.solve;
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let _ = sender.send(solve(&text, &[]));
+        });
+
+        match receiver.recv_timeout(BUDGET) {
+            // A generated input may have no answer, e.g. no Christmas tree for day 14.
+            Ok(Ok(_) | Err(Error::NoAnswer(_))) => {}
+            Ok(Err(error)) => panic!(
+                "day {} part {} fails on {}: {}",
+                solution.day, solution.part, name, error
+            ),
+            Err(_) => panic!(
+                "day {} part {} takes longer than {}s on {}",
+                solution.day,
+                solution.part,
+                BUDGET.as_secs(),
+                name
+            ),
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Day 1 for location lists too large to hold in memory, see [`day_1::stream`].
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::PathBuf;
+
+use aoc_common::cli::INPUT_USAGE;
+use aoc_common::{example_path, input_path, Error, InputSource, OrExit, Solution};
+use day_1::stream::{self, Budget, DEFAULT_BUDGET};
+use day_1::Day1;
+
+const USAGE: &str = "Usage: day-1-stream [1 | 2] [--input <path> | --example] [<options>]";
+
+const BUDGET_USAGE: &str =
+    "    --memory <size>   keep at most <size> bytes of IDs in memory, e.g. 512K, 64M or 2G, 64M by
+                      default
+    --spill-dir <path>
+                      write the sorted runs that do not fit in memory under <path> instead of
+                      the temporary directory";
+
+fn main() {
+    run().or_exit();
+}
+
+fn run() -> aoc_common::Result<()> {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let with_usage = |error| match error {
+        Error::Usage(message) => {
+            let options = format!("Options:\n{}\n{}", INPUT_USAGE, BUDGET_USAGE);
+            Error::Usage(format!("{}\n\n{}\n\n{}", message, USAGE, options))
+        }
+        other => other,
+    };
+
+    let source = InputSource::extract(&mut args).map_err(with_usage)?;
+    let mut budget = Budget::new(DEFAULT_BUDGET);
+    if let Some(size) = take_option(&mut args, "--memory").map_err(with_usage)? {
+        budget.bytes = parse_size(&size).map_err(with_usage)?;
+    }
+    if let Some(path) = take_option(&mut args, "--spill-dir").map_err(with_usage)? {
+        budget.spill_dir = PathBuf::from(path);
+    }
+    let parts = match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        [] => vec![1, 2],
+        ["1"] => vec![1],
+        ["2"] => vec![2],
+        [unknown, ..] => {
+            return Err(with_usage(Error::Usage(format!(
+                "unrecognized argument {:?}",
+                unknown
+            ))))
+        }
+    };
+
+    // Both parts are answered in one pass, so that stdin only has to be read once.
+    let (name, reader): (String, Box<dyn BufRead>) = match source {
+        InputSource::Stdin => ("<stdin>".to_string(), Box::new(io::stdin().lock())),
+        source => {
+            let path = match source {
+                InputSource::File(path) => path,
+                InputSource::Example => input_path(example_path(Day1::DAY, parts[0])?)?,
+                _ => input_path(Day1::INPUT_PATH)?,
+            };
+            let file = File::open(&path).map_err(|source| Error::Read {
+                path: path.clone(),
+                source,
+            })?;
+            (path.display().to_string(), Box::new(BufReader::new(file)))
+        }
+    };
+
+    let answers = stream::solve(reader, &name, &parts, &budget)?;
+    for (part, answer) in parts.iter().zip(answers) {
+        let label = if *part == 1 { "Sum: " } else { "" };
+        println!("{}{}", label, answer);
+    }
+    Ok(())
+}
+
+/// Takes `option <value>` out of `args`.
+fn take_option(args: &mut Vec<String>, option: &str) -> aoc_common::Result<Option<String>> {
+    let Some(index) = args.iter().position(|arg| arg == option) else {
+        return Ok(None);
+    };
+    args.remove(index);
+    if index >= args.len() {
+        return Err(Error::Usage(format!("{} needs a value", option)));
+    }
+    Ok(Some(args.remove(index)))
+}
+
+/// A number of bytes, with an optional `K`, `M` or `G` for binary kilo-, mega- or gigabytes.
+fn parse_size(size: &str) -> aoc_common::Result<usize> {
+    let (digits, shift) = [('K', 10), ('M', 20), ('G', 30)]
+        .into_iter()
+        .find_map(|(suffix, shift)| Some((size.strip_suffix(suffix)?, shift)))
+        .unwrap_or((size, 0));
+
+    digits
+        .parse::<usize>()
+        .ok()
+        .and_then(|number| number.checked_mul(1 << shift))
+        .filter(|&bytes| bytes > 0)
+        .ok_or_else(|| {
+            Error::Usage(format!(
+                "--memory needs a size in bytes like 512K, 64M or 2G, got {:?}",
+                size
+            ))
+        })
+}
@@ -0,0 +1,107 @@
+use aoc_common::{Answer, Header, Override, Param, Solution};
+
+use lists::Metric;
+
+pub mod lists;
+mod reference;
+pub mod stream;
+
+pub struct Day1;
+
+/// The puzzle parameters, see [`Day1::PARAMS`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    /// How part 1 compares the lists.
+    pub part1_metric: Metric,
+    /// How part 2 compares the lists.
+    pub part2_metric: Metric,
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const INPUT_PATH: &'static str = "day-1/input.txt";
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "part1_metric",
+            doc: "how part 1 compares the lists: sorted-l1, sorted-l2, max-difference, \
+                  similarity, intersection or difference",
+            default: "sorted-l1",
+        },
+        Param {
+            name: "part2_metric",
+            doc: "how part 2 compares the lists, like part1_metric",
+            default: "similarity",
+        },
+    ];
+
+    /// The lists, one per column of the input.
+    type Input = (Vec<Vec<u32>>, Params);
+
+    fn parse_with(input: &str, overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        let (header, columns) = Header::split(input, Self::PARAMS, overrides)?;
+        let params = Params {
+            part1_metric: header.get_with("part1_metric", str::parse)?,
+            part2_metric: header.get_with("part2_metric", str::parse)?,
+        };
+        Ok((lists::parse_columns(input, columns)?, params))
+    }
+
+    fn part1((lists, params): &Self::Input) -> aoc_common::Result<Answer> {
+        compare(params.part1_metric, lists)
+    }
+
+    fn part2((lists, params): &Self::Input) -> aoc_common::Result<Answer> {
+        compare(params.part2_metric, lists)
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part2(input))
+    }
+}
+
+/// One number for two lists, a matrix of every pair for more.
+fn compare(metric: Metric, lists: &[Vec<u32>]) -> aoc_common::Result<Answer> {
+    match lists {
+        [] | [_] => Err(lists::too_few_lists(lists)),
+        [first, second] => Ok(lists::compare(metric, first, second)?.into()),
+        _ => Ok(lists::render_matrix(&lists::matrix(metric, lists)?).into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::testing::{answer, reference};
+    use aoc_common::Error;
+
+    use super::*;
+
+    #[test]
+    fn more_lists_give_a_matrix() {
+        let input = "part1_metric=max-difference\n1 2 7\n3 5 7\n";
+
+        assert_eq!(answer(Day1::solve_part1, input), "0 2 6; 2 0 5; 6 5 0");
+        assert_eq!(answer(Day1::solve_part2, input), "4 0 0; 0 7 0; 0 0 28");
+        assert_eq!(
+            reference(Day1::reference_part2, input),
+            "4 0 0; 0 7 0; 0 0 28"
+        );
+    }
+
+    #[test]
+    fn unequal_lists_and_unknown_metrics_are_errors() {
+        assert!(matches!(Day1::parse("1 2 3\n4 5\n"), Err(Error::Parse(_))));
+        assert!(matches!(Day1::parse("1 2\n4 5 6\n"), Err(Error::Parse(_))));
+        assert!(matches!(
+            Day1::parse("part1_metric=l3\n1 2\n"),
+            Err(Error::Parse(_))
+        ));
+        assert!(matches!(
+            Day1::solve_part1("1\n2\n"),
+            Err(Error::NoAnswer(_))
+        ));
+    }
+}
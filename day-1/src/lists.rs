@@ -0,0 +1,243 @@
+//! Comparing any number of location lists, given as the columns of the input, with a choice of
+//! metrics. Two lists give one number, more lists a matrix with a number for every pair.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+use aoc_common::{parse, Error, ParseError};
+
+/// How two lists are compared. The sorted metrics pair the smallest ID of one list with the
+/// smallest of the other, and so on, so they need lists of the same length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// The sum of the differences of the pairs, part 1 of the puzzle.
+    SortedL1,
+    /// The sum of the squared differences of the pairs, the square of their L2 distance.
+    SortedL2,
+    /// The largest difference of a pair.
+    MaxDifference,
+    /// Every ID of the first list times how often it is in the second, part 2 of the puzzle.
+    Similarity,
+    /// How many IDs both lists have, counting repeated IDs as often as both have them.
+    Intersection,
+    /// How many IDs of the first list are left over after taking out those of the second.
+    Difference,
+}
+
+pub const METRICS: &str =
+    "sorted-l1, sorted-l2, max-difference, similarity, intersection or difference";
+
+impl Metric {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Metric::SortedL1 => "sorted-l1",
+            Metric::SortedL2 => "sorted-l2",
+            Metric::MaxDifference => "max-difference",
+            Metric::Similarity => "similarity",
+            Metric::Intersection => "intersection",
+            Metric::Difference => "difference",
+        }
+    }
+
+    fn is_sorted(self) -> bool {
+        matches!(
+            self,
+            Metric::SortedL1 | Metric::SortedL2 | Metric::MaxDifference
+        )
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Metric, String> {
+        match name {
+            "sorted-l1" => Ok(Metric::SortedL1),
+            "sorted-l2" => Ok(Metric::SortedL2),
+            "max-difference" => Ok(Metric::MaxDifference),
+            "similarity" => Ok(Metric::Similarity),
+            "intersection" => Ok(Metric::Intersection),
+            "difference" => Ok(Metric::Difference),
+            _ => Err(format!("expected {}", METRICS)),
+        }
+    }
+}
+
+impl fmt::Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The columns of `columns`, the part of `input` after its header, each one a list. Every line
+/// has to have as many IDs as the first one, otherwise the lists would be of different lengths.
+pub fn parse_columns(input: &str, columns: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    let mut lists: Vec<Vec<u32>> = vec![];
+
+    for (index, line) in columns.lines().enumerate() {
+        let ids = line
+            .split_whitespace()
+            .map(|token| parse::number::<u32>(input, token))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if index == 0 {
+            if ids.is_empty() {
+                return Err(ParseError::unexpected_end_of(input, line, "a location ID"));
+            }
+            lists = vec![vec![]; ids.len()];
+        } else if ids.len() != lists.len() {
+            return Err(ParseError::invalid(
+                input,
+                line,
+                format!(
+                    "the first line has {} location IDs and this one {}, so the lists would be \
+                     of different lengths",
+                    lists.len(),
+                    ids.len()
+                ),
+            ));
+        }
+
+        for (list, id) in lists.iter_mut().zip(ids) {
+            list.push(id);
+        }
+    }
+
+    Ok(lists)
+}
+
+/// Compares `first` with `second`. Similarity, intersection and difference depend on which list
+/// comes first.
+pub fn compare(metric: Metric, first: &[u32], second: &[u32]) -> aoc_common::Result<u64> {
+    if metric.is_sorted() && first.len() != second.len() {
+        return Err(Error::NoAnswer(format!(
+            "{} pairs the IDs one to one, but the lists have {} and {} IDs",
+            metric,
+            first.len(),
+            second.len()
+        )));
+    }
+    let too_large = || too_large(metric);
+
+    match metric {
+        Metric::SortedL1 | Metric::SortedL2 | Metric::MaxDifference => {
+            let mut first = first.to_vec();
+            let mut second = second.to_vec();
+            first.sort_unstable();
+            second.sort_unstable();
+            let mut differences = first
+                .iter()
+                .zip(&second)
+                .map(|(a, b)| u64::from(a.abs_diff(*b)));
+
+            match metric {
+                Metric::SortedL1 => differences
+                    .try_fold(0u64, |sum, difference| sum.checked_add(difference))
+                    .ok_or_else(too_large),
+                Metric::SortedL2 => differences
+                    .try_fold(0u64, |sum, difference| {
+                        sum.checked_add(difference * difference)
+                    })
+                    .ok_or_else(too_large),
+                _ => Ok(differences.max().unwrap_or(0)),
+            }
+        }
+        Metric::Similarity => {
+            let counts = counts(second);
+            first
+                .iter()
+                .try_fold(0u64, |sum, id| {
+                    let count = counts.get(id).copied().unwrap_or(0);
+                    sum.checked_add(u64::from(*id) * count)
+                })
+                .ok_or_else(too_large)
+        }
+        Metric::Intersection | Metric::Difference => {
+            let mut counts = counts(second);
+            let mut common = 0u64;
+            for id in first {
+                if let Some(count) = counts.get_mut(id).filter(|count| **count > 0) {
+                    *count -= 1;
+                    common += 1;
+                }
+            }
+            match metric {
+                Metric::Intersection => Ok(common),
+                _ => Ok(first.len() as u64 - common),
+            }
+        }
+    }
+}
+
+/// `compare` for every pair of lists, row `i` and column `j` comparing list `i` with list `j`.
+pub fn matrix(metric: Metric, lists: &[Vec<u32>]) -> aoc_common::Result<Vec<Vec<u64>>> {
+    lists
+        .iter()
+        .map(|first| {
+            lists
+                .iter()
+                .map(|second| compare(metric, first, second))
+                .collect()
+        })
+        .collect()
+}
+
+/// The rows of a matrix on one line, so that it fits in the answer table of `aoc run`:
+/// `0 11 7; 11 0 4; 7 4 0`.
+pub fn render_matrix(matrix: &[Vec<u64>]) -> String {
+    matrix
+        .iter()
+        .map(|row| row.iter().map(u64::to_string).collect::<Vec<_>>().join(" "))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// The error for an input with fewer than two lists, which leaves nothing to compare.
+pub fn too_few_lists(lists: &[Vec<u32>]) -> Error {
+    Error::NoAnswer(format!(
+        "comparing needs at least two lists, the input has {}",
+        lists.len()
+    ))
+}
+
+/// The error for a comparison whose result overflows.
+pub fn too_large(metric: Metric) -> Error {
+    Error::NoAnswer(format!("the {} does not fit in 64 bits", metric))
+}
+
+fn counts(ids: &[u32]) -> HashMap<u32, u64> {
+    let mut counts = HashMap::new();
+    for &id in ids {
+        *counts.entry(id).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metrics_compare_two_lists() {
+        let left = [3, 4, 2, 1, 3, 3];
+        let right = [4, 3, 5, 3, 9, 3];
+        let compared = |metric| compare(metric, &left, &right).unwrap();
+
+        assert_eq!(compared(Metric::SortedL1), 11);
+        assert_eq!(compared(Metric::SortedL2), 35);
+        assert_eq!(compared(Metric::MaxDifference), 5);
+        assert_eq!(compared(Metric::Similarity), 31);
+        assert_eq!(compared(Metric::Intersection), 4);
+        assert_eq!(compared(Metric::Difference), 2);
+    }
+
+    #[test]
+    fn sorted_metrics_need_lists_of_the_same_length() {
+        assert!(matches!(
+            compare(Metric::SortedL1, &[1, 2, 3], &[1, 2]),
+            Err(Error::NoAnswer(_))
+        ));
+        assert_eq!(compare(Metric::Difference, &[1, 2, 3], &[1, 2]).unwrap(), 1);
+    }
+}
@@ -0,0 +1,5 @@
+use aoc_common::OrExit;
+
+fn main() {
+    aoc_common::run_day::<day_1::Day1>(["Sum: ", ""]).or_exit();
+}
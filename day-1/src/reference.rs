@@ -0,0 +1,65 @@
+//! Slow but obviously correct solutions, checked against the fast ones by `aoc check`. They only
+//! know the metric of the puzzle's part, and ignore the metric parameters.
+
+use aoc_common::{Answer, Solution};
+
+use crate::{lists, Day1};
+
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    compare_all(input, distance)
+}
+
+pub fn part2(input: &str) -> aoc_common::Result<Answer> {
+    compare_all(input, similarity)
+}
+
+/// `compare` on two lists, or on every pair of more lists.
+fn compare_all(input: &str, compare: fn(Vec<u32>, Vec<u32>) -> u64) -> aoc_common::Result<Answer> {
+    let (lists, _) = Day1::parse(input)?;
+
+    match lists.as_slice() {
+        [] | [_] => Err(lists::too_few_lists(&lists)),
+        [left, right] => Ok(compare(left.clone(), right.clone()).into()),
+        _ => {
+            let matrix = lists
+                .iter()
+                .map(|left| {
+                    lists
+                        .iter()
+                        .map(|right| compare(left.clone(), right.clone()))
+                        .collect()
+                })
+                .collect::<Vec<_>>();
+            Ok(lists::render_matrix(&matrix).into())
+        }
+    }
+}
+
+/// Pairs the smallest remaining IDs of both lists, one pair at a time.
+fn distance(mut left: Vec<u32>, mut right: Vec<u32>) -> u64 {
+    let mut total = 0u64;
+    while let (Some(smallest_left), Some(smallest_right)) = (smallest(&left), smallest(&right)) {
+        total += u64::from(
+            left.remove(smallest_left)
+                .abs_diff(right.remove(smallest_right)),
+        );
+    }
+    total
+}
+
+/// Counts every ID of the right list for every ID of the left one.
+fn similarity(left: Vec<u32>, right: Vec<u32>) -> u64 {
+    let mut total = 0u64;
+    for &id in &left {
+        for &other in &right {
+            if id == other {
+                total += u64::from(id);
+            }
+        }
+    }
+    total
+}
+
+fn smallest(ids: &[u32]) -> Option<usize> {
+    (0..ids.len()).min_by_key(|&index| ids[index])
+}
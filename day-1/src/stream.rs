@@ -0,0 +1,692 @@
+//! Both parts for location lists too large to hold in memory, read one line at a time.
+//!
+//! Part 1 sorts each list with an external merge sort: whenever the IDs kept in memory reach the
+//! budget, they are sorted and spilled to a temporary file as a run, and the runs are merged at
+//! the end. Part 2 counts how often every ID is in each list in a map, spilled the same way as
+//! runs sorted by ID once it outgrows the budget. Without spilling, both give the answers of
+//! [`Day1`](crate::Day1) for two lists and the puzzle's metrics.
+//!
+//! Merging takes a read buffer per open run and a write buffer, all out of the budget, so only as
+//! many runs are merged at once as their buffers fit in it. When there are more runs, groups of
+//! them are merged into longer runs first, in as many passes as it takes.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::env;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use aoc_common::{Error, Header, ParseError, Solution};
+
+use crate::lists::{self, Metric};
+use crate::Day1;
+
+/// 64 MiB.
+pub const DEFAULT_BUDGET: usize = 64 << 20;
+
+/// What the map of part 2 takes per ID, with the overhead of the hash table.
+const COUNT_BYTES: usize = 32;
+
+/// The largest buffer for reading or writing a run.
+const BUFFER_BYTES: usize = 8 << 10;
+
+/// How much memory the IDs, and the buffers of the runs, may take, and where what does not fit is
+/// spilled. A budget too small for the buffers of two runs and the one they are merged into still
+/// merges two at a time, with buffers of one record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Budget {
+    pub bytes: usize,
+    pub spill_dir: PathBuf,
+}
+
+impl Budget {
+    /// `bytes` of memory, spilling to the temporary directory of the system.
+    pub fn new(bytes: usize) -> Budget {
+        Budget {
+            bytes,
+            spill_dir: env::temp_dir(),
+        }
+    }
+}
+
+impl Default for Budget {
+    fn default() -> Budget {
+        Budget::new(DEFAULT_BUDGET)
+    }
+}
+
+/// Reads the two lists from `reader`, named `name` in errors, and answers each of `parts` in the
+/// same pass.
+pub fn solve(
+    reader: impl BufRead,
+    name: &str,
+    parts: &[u8],
+    budget: &Budget,
+) -> aoc_common::Result<Vec<u64>> {
+    // The parts solved together share the budget.
+    let budget = Budget {
+        bytes: budget.bytes / parts.len().max(1),
+        spill_dir: budget.spill_dir.clone(),
+    };
+    let mut distance = parts
+        .contains(&1)
+        .then(|| [Column::new(&budget), Column::new(&budget)]);
+    let mut similarity = parts.contains(&2).then(|| Counts::new(&budget));
+
+    let lines = for_each_pair(reader, name, parts, |left, right| {
+        if let Some([lefts, rights]) = &mut distance {
+            lefts.push(left)?;
+            rights.push(right)?;
+        }
+        if let Some(counts) = &mut similarity {
+            counts.push(left, right)?;
+        }
+        Ok(())
+    })?;
+    if lines == 0 {
+        return Err(lists::too_few_lists(&[]));
+    }
+
+    let mut answers = vec![];
+    for &part in parts {
+        let answer = match part {
+            1 => distance
+                .take()
+                .map(|[lefts, rights]| sorted_distance(lefts, rights)),
+            _ => similarity.take().map(Counts::similarity),
+        };
+        answers.push(answer.expect("every part is solved once")?);
+    }
+    Ok(answers)
+}
+
+/// Calls `f` with the IDs of every line after the header, returning how many such lines there
+/// were. The header is split off like [`Header::split`] does, and checked for `parts`.
+fn for_each_pair(
+    mut reader: impl BufRead,
+    name: &str,
+    parts: &[u8],
+    mut f: impl FnMut(u32, u32) -> aoc_common::Result<()>,
+) -> aoc_common::Result<usize> {
+    let mut line = String::new();
+    let mut number = 0;
+    let mut pairs = 0;
+    // Until the first line that is not a parameter.
+    let mut header = Some(String::new());
+
+    loop {
+        line.clear();
+        let read = reader.read_line(&mut line).map_err(|source| Error::Read {
+            path: PathBuf::from(name),
+            source,
+        })?;
+        number += 1;
+
+        if let Some(text) = &mut header {
+            text.push_str(&line);
+            let (_, rest) = Header::split(text, Day1::PARAMS, &[])
+                .map_err(|error| Error::from(error.in_file(name)))?;
+            if read != 0 && rest.is_empty() {
+                continue;
+            }
+            let length = text.len() - rest.len();
+            let text = header.take().expect("the header is read");
+            check_header(&text[..length], parts)
+                .map_err(|error| Error::from(error.in_file(name)))?;
+        }
+        if read == 0 {
+            return Ok(pairs);
+        }
+        pairs += 1;
+
+        let text = line.trim_end_matches(['\n', '\r']);
+        let columns = lists::parse_columns(text, text)
+            .map_err(|error| Error::from(error.on_line(number).in_file(name)))?;
+        match columns.as_slice() {
+            [left, right] => f(left[0], right[0])?,
+            _ => {
+                let error = ParseError::invalid(
+                    text,
+                    text,
+                    "streaming compares two lists, so every line needs two location IDs",
+                );
+                return Err(error.on_line(number).in_file(name).into());
+            }
+        }
+    }
+}
+
+/// An error for a metric in `header` that one of `parts` is to use and streaming does not know,
+/// which are all but the puzzle's.
+fn check_header(header: &str, parts: &[u8]) -> Result<(), ParseError> {
+    let (params, _) = Header::split(header, Day1::PARAMS, &[])?;
+    for &part in parts {
+        let (param, streamed) = match part {
+            1 => ("part1_metric", Metric::SortedL1),
+            _ => ("part2_metric", Metric::Similarity),
+        };
+        if params.get_with(param, str::parse::<Metric>)? != streamed {
+            let value = params.value_in_input(param).unwrap_or(&header[..0]);
+            return Err(ParseError::invalid(
+                header,
+                value,
+                format!("streaming part {} only compares with {}", part, streamed),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Sums the differences of the pairs of the sorted lists.
+fn sorted_distance(lefts: Column, rights: Column) -> aoc_common::Result<u64> {
+    let mut total = 0u64;
+    for (left, right) in lefts.into_sorted()?.zip(rights.into_sorted()?) {
+        let difference = u64::from(left?.abs_diff(right?));
+        total = total
+            .checked_add(difference)
+            .ok_or_else(|| lists::too_large(Metric::SortedL1))?;
+    }
+    Ok(total)
+}
+
+/// One list, partly in memory and partly in sorted runs.
+struct Column {
+    ids: Vec<u32>,
+    capacity: usize,
+    runs: Runs,
+}
+
+impl Column {
+    fn new(budget: &Budget) -> Column {
+        // Both lists share the budget, and are merged at the same time.
+        let bytes = budget.bytes / 2;
+        Column {
+            ids: vec![],
+            capacity: (bytes / 4).max(1),
+            runs: Runs::new(bytes, &budget.spill_dir),
+        }
+    }
+
+    fn push(&mut self, id: u32) -> aoc_common::Result<()> {
+        if self.ids.len() == self.capacity {
+            self.ids.sort_unstable();
+            self.runs.spill(&self.ids)?;
+            self.ids.clear();
+        }
+        self.ids.push(id);
+        Ok(())
+    }
+
+    fn into_sorted(mut self) -> aoc_common::Result<Merge<u32>> {
+        self.ids.sort_unstable();
+        self.runs.merge(self.ids)
+    }
+}
+
+/// How often every ID is in the left and the right list.
+struct Counts {
+    counts: HashMap<u32, (u64, u64)>,
+    capacity: usize,
+    runs: Runs,
+}
+
+impl Counts {
+    fn new(budget: &Budget) -> Counts {
+        Counts {
+            counts: HashMap::new(),
+            capacity: (budget.bytes / COUNT_BYTES).max(1),
+            runs: Runs::new(budget.bytes, &budget.spill_dir),
+        }
+    }
+
+    fn push(&mut self, left: u32, right: u32) -> aoc_common::Result<()> {
+        for (id, is_left) in [(left, true), (right, false)] {
+            if self.counts.len() == self.capacity && !self.counts.contains_key(&id) {
+                let counts = self.sorted();
+                self.runs.spill(&counts)?;
+                self.counts.clear();
+            }
+            let (lefts, rights) = self.counts.entry(id).or_default();
+            if is_left {
+                *lefts += 1;
+            } else {
+                *rights += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn sorted(&self) -> Vec<(u32, u64, u64)> {
+        let mut counts = self
+            .counts
+            .iter()
+            .map(|(&id, &(lefts, rights))| (id, lefts, rights))
+            .collect::<Vec<_>>();
+        counts.sort_unstable();
+        counts
+    }
+
+    /// Every ID times how often it is in the left list and how often in the right one. The runs
+    /// are merged by ID, as one ID can be counted in several of them.
+    fn similarity(self) -> aoc_common::Result<u64> {
+        let counts = self.sorted();
+        drop(self.counts);
+        let mut total = 0u64;
+        let mut merged = self.runs.merge(counts)?.peekable();
+
+        while let Some(next) = merged.next() {
+            let (id, mut lefts, mut rights) = next?;
+            while let Some(Ok((_, more_lefts, more_rights))) =
+                merged.next_if(|next| matches!(next, Ok((next_id, _, _)) if *next_id == id))
+            {
+                lefts += more_lefts;
+                rights += more_rights;
+            }
+            total = u64::from(id)
+                .checked_mul(lefts)
+                .and_then(|score| score.checked_mul(rights))
+                .and_then(|score| total.checked_add(score))
+                .ok_or_else(|| lists::too_large(Metric::Similarity))?;
+        }
+        Ok(total)
+    }
+}
+
+/// A value that a run stores in a fixed number of bytes.
+trait Record: Copy + Ord {
+    const BYTES: usize;
+
+    fn write(self, writer: &mut impl Write) -> io::Result<()>;
+
+    fn read(bytes: &[u8]) -> Self;
+}
+
+impl Record for u32 {
+    const BYTES: usize = 4;
+
+    fn write(self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(&self.to_le_bytes())
+    }
+
+    fn read(bytes: &[u8]) -> u32 {
+        u32::from_le_bytes(bytes.try_into().unwrap())
+    }
+}
+
+impl Record for (u32, u64, u64) {
+    const BYTES: usize = 20;
+
+    fn write(self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(&self.0.to_le_bytes())?;
+        writer.write_all(&self.1.to_le_bytes())?;
+        writer.write_all(&self.2.to_le_bytes())
+    }
+
+    fn read(bytes: &[u8]) -> (u32, u64, u64) {
+        (
+            u32::from_le_bytes(bytes[..4].try_into().unwrap()),
+            u64::from_le_bytes(bytes[4..12].try_into().unwrap()),
+            u64::from_le_bytes(bytes[12..].try_into().unwrap()),
+        )
+    }
+}
+
+/// The runs spilled from one list or one map, and the memory they are merged in.
+struct Runs {
+    runs: Vec<Run>,
+    spill_dir: PathBuf,
+    bytes: usize,
+}
+
+impl Runs {
+    fn new(bytes: usize, spill_dir: &Path) -> Runs {
+        Runs {
+            runs: vec![],
+            spill_dir: spill_dir.to_path_buf(),
+            bytes,
+        }
+    }
+
+    /// The size of a buffer, when two runs and the one they are merged into need one each.
+    fn buffer<R: Record>(&self) -> usize {
+        (self.bytes / 3).clamp(R::BYTES, BUFFER_BYTES)
+    }
+
+    /// How many runs are merged at once, besides the run or the records they are merged into.
+    fn fan_in<R: Record>(&self) -> usize {
+        (self.bytes / self.buffer::<R>()).saturating_sub(1).max(2)
+    }
+
+    fn spill<R: Record>(&mut self, records: &[R]) -> aoc_common::Result<()> {
+        let records = records.iter().copied().map(Ok);
+        let run = Run::write(&self.spill_dir, self.buffer::<R>(), records)?;
+        self.runs.push(run);
+        Ok(())
+    }
+
+    /// Every record of the runs and of `memory`, sorted, in order. Once there are runs, `memory`
+    /// is spilled too, to leave the budget to the buffers.
+    fn merge<R: Record>(mut self, memory: Vec<R>) -> aoc_common::Result<Merge<R>> {
+        let (buffer, fan_in) = (self.buffer::<R>(), self.fan_in::<R>());
+        if self.runs.is_empty() {
+            return Merge::new(vec![], memory, buffer);
+        }
+        if !memory.is_empty() {
+            self.spill(&memory)?;
+            drop(memory);
+        }
+
+        aoc_common::debug!(
+            "merging {} runs, {} at a time with {} byte buffers",
+            self.runs.len(),
+            fan_in,
+            buffer
+        );
+        // Merging the oldest runs first merges every record about as often.
+        let mut runs = self.runs.into_iter().collect::<VecDeque<_>>();
+        while runs.len() > fan_in {
+            let group = runs.drain(..fan_in).collect();
+            let merged = Merge::<R>::new(group, vec![], buffer)?;
+            runs.push_back(Run::write(&self.spill_dir, buffer, merged)?);
+        }
+        Merge::new(runs.into(), vec![], buffer)
+    }
+}
+
+/// Tells the runs of this process apart.
+static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+/// A sorted run in a temporary file, deleted when dropped.
+struct Run {
+    path: PathBuf,
+}
+
+impl Run {
+    /// Writes `records` through a buffer of `buffer` bytes.
+    fn write<R: Record>(
+        spill_dir: &Path,
+        buffer: usize,
+        records: impl IntoIterator<Item = aoc_common::Result<R>>,
+    ) -> aoc_common::Result<Run> {
+        let name = format!(
+            "day-1-{}-{}.run",
+            process::id(),
+            RUNS.fetch_add(1, Ordering::Relaxed)
+        );
+        let run = Run {
+            path: spill_dir.join(name),
+        };
+        let error = |source| Error::Write {
+            path: run.path.clone(),
+            source,
+        };
+
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&run.path)
+            .map_err(error)?;
+        let mut writer = BufWriter::with_capacity(buffer, file);
+        for record in records {
+            record?.write(&mut writer).map_err(error)?;
+        }
+        writer.flush().map_err(error)?;
+        Ok(run)
+    }
+}
+
+impl Drop for Run {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Where [`Merge`] takes its records from.
+enum Source<R> {
+    Memory(std::vec::IntoIter<R>),
+    File(Run, BufReader<File>),
+}
+
+impl<R: Record> Source<R> {
+    /// The next record, `None` at the end of the run. A run that ends partway through a record
+    /// was cut off, and is an error rather than an end that would drop the records after it.
+    fn next(&mut self) -> aoc_common::Result<Option<R>> {
+        let (run, reader) = match self {
+            Source::Memory(records) => return Ok(records.next()),
+            Source::File(run, reader) => (run, reader),
+        };
+        let error = |source| Error::Read {
+            path: run.path.clone(),
+            source,
+        };
+
+        if reader.fill_buf().map_err(error)?.is_empty() {
+            return Ok(None);
+        }
+        let mut bytes = [0; 20];
+        let bytes = &mut bytes[..R::BYTES];
+        match reader.read_exact(bytes) {
+            Ok(()) => Ok(Some(R::read(bytes))),
+            Err(source) if source.kind() == io::ErrorKind::UnexpectedEof => {
+                Err(error(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "the run ends partway through a record",
+                )))
+            }
+            Err(source) => Err(error(source)),
+        }
+    }
+}
+
+/// The records of sorted runs and of a sorted vector, in order.
+struct Merge<R> {
+    sources: Vec<Source<R>>,
+    /// The next record of every source that has one left.
+    heads: BinaryHeap<Reverse<(R, usize)>>,
+}
+
+impl<R: Record> Merge<R> {
+    /// Opens every run in `runs`, each with a buffer of `buffer` bytes.
+    fn new(runs: Vec<Run>, memory: Vec<R>, buffer: usize) -> aoc_common::Result<Merge<R>> {
+        let mut sources = vec![Source::Memory(memory.into_iter())];
+        for run in runs {
+            let file = File::open(&run.path).map_err(|source| Error::Read {
+                path: run.path.clone(),
+                source,
+            })?;
+            sources.push(Source::File(run, BufReader::with_capacity(buffer, file)));
+        }
+
+        let mut heads = BinaryHeap::new();
+        for (index, source) in sources.iter_mut().enumerate() {
+            if let Some(record) = source.next()? {
+                heads.push(Reverse((record, index)));
+            }
+        }
+        Ok(Merge { sources, heads })
+    }
+}
+
+impl<R: Record> Iterator for Merge<R> {
+    type Item = aoc_common::Result<R>;
+
+    fn next(&mut self) -> Option<aoc_common::Result<R>> {
+        let Reverse((record, index)) = self.heads.pop()?;
+        match self.sources[index].next() {
+            Ok(Some(next)) => self.heads.push(Reverse((next, index))),
+            Ok(None) => {}
+            Err(error) => return Some(Err(error)),
+        }
+        Some(Ok(record))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use aoc_common::testing::answer;
+    use aoc_common::{Rng, Solution};
+
+    use super::*;
+    use crate::Day1;
+
+    /// `size` pairs of IDs, a third of the right ones also in the left list.
+    fn location_lists(rng: &mut Rng, size: usize) -> String {
+        let left = (0..size)
+            .map(|_| rng.between(10000, 99999))
+            .collect::<Vec<_>>();
+
+        let mut input = String::new();
+        for &id in &left {
+            let right = match rng.chance(1, 3) {
+                true => *rng.choose(&left),
+                false => rng.between(10000, 99999),
+            };
+            let _ = writeln!(input, "{}   {}", id, right);
+        }
+        input
+    }
+
+    /// An empty directory of its own for the runs of one test.
+    fn spill_dir(test: &str) -> PathBuf {
+        let spill_dir = env::temp_dir().join(format!("day-1-{}-{}", test, process::id()));
+        std::fs::create_dir_all(&spill_dir).unwrap();
+        spill_dir
+    }
+
+    /// Checks that every run was deleted once merged, and removes the directory.
+    fn remove_spill_dir(spill_dir: &Path) {
+        assert_eq!(std::fs::read_dir(spill_dir).unwrap().count(), 0);
+        std::fs::remove_dir(spill_dir).unwrap();
+    }
+
+    #[test]
+    fn streaming_gives_the_answers_of_the_lists_in_memory() {
+        let spill_dir = spill_dir("stream");
+
+        for seed in 0..20 {
+            let input = location_lists(&mut Rng::new(seed), 1 + seed as usize * 10);
+            let expected = [
+                answer(Day1::solve_part1, &input),
+                answer(Day1::solve_part2, &input),
+            ];
+
+            // From spilling every ID to keeping all of them in memory.
+            for bytes in [1, 48, 1000, DEFAULT_BUDGET] {
+                let budget = Budget {
+                    bytes,
+                    spill_dir: spill_dir.clone(),
+                };
+                for parts in [&[1, 2][..], &[1], &[2]] {
+                    let answers = solve(input.as_bytes(), "input", parts, &budget).unwrap();
+                    for (part, answer) in parts.iter().zip(answers) {
+                        assert_eq!(answer.to_string(), expected[*part as usize - 1]);
+                    }
+                }
+            }
+        }
+
+        remove_spill_dir(&spill_dir);
+    }
+
+    #[test]
+    fn streaming_merges_many_runs_in_several_passes() {
+        let spill_dir = spill_dir("passes");
+
+        // Hundreds of runs per list, of which the buffers of only two fit in the budget at once.
+        let input = location_lists(&mut Rng::new(7), 5000);
+        let budget = Budget {
+            bytes: 256,
+            spill_dir: spill_dir.clone(),
+        };
+        let answers = solve(input.as_bytes(), "input", &[1, 2], &budget).unwrap();
+        assert_eq!(
+            answers.iter().map(u64::to_string).collect::<Vec<_>>(),
+            [
+                answer(Day1::solve_part1, &input),
+                answer(Day1::solve_part2, &input)
+            ]
+        );
+
+        remove_spill_dir(&spill_dir);
+    }
+
+    #[test]
+    fn a_run_cut_off_in_a_record_is_an_error() {
+        let spill_dir = spill_dir("cut-off");
+        let run = Run::write(&spill_dir, 64, [1u32, 2, 3].map(Ok)).unwrap();
+        let file = OpenOptions::new().write(true).open(&run.path).unwrap();
+        file.set_len(10).unwrap();
+
+        let mut merged = Merge::<u32>::new(vec![run], vec![], 64).unwrap();
+        assert_eq!(merged.next().unwrap().unwrap(), 1);
+        assert!(matches!(merged.next(), Some(Err(Error::Read { .. }))));
+
+        drop(merged);
+        remove_spill_dir(&spill_dir);
+    }
+
+    #[test]
+    fn streaming_splits_off_the_header_like_the_lists_in_memory() {
+        let budget = Budget::default();
+        let inputs = [
+            "part1_metric=sorted-l1\n\npart2_metric=similarity\n3   4\n4   3\n2   5\n",
+            "part2_metric=similarity\n",
+            "part1_metric=sorted-l1\n1   2\npart2_metric=similarity\n",
+        ];
+
+        for input in inputs {
+            let streamed = solve(input.as_bytes(), "input", &[1, 2], &budget);
+            let in_memory = [Day1::solve_part1(input), Day1::solve_part2(input)];
+            match (streamed, in_memory) {
+                (Ok(streamed), [Ok(part1), Ok(part2)]) => {
+                    assert_eq!(
+                        streamed,
+                        [part1, part2].map(|answer| answer.to_string().parse::<u64>().unwrap()),
+                        "{:?}",
+                        input
+                    );
+                }
+                (Err(Error::Parse(_)), [Err(Error::Parse(_)), _]) => {}
+                (Err(Error::NoAnswer(_)), [Err(Error::NoAnswer(_)), _]) => {}
+                (streamed, in_memory) => {
+                    panic!("{:?} gives {:?} and {:?}", input, streamed, in_memory)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn streaming_refuses_the_metrics_it_does_not_know() {
+        let input = "part2_metric=intersection\n3   4\n4   3\n";
+        let budget = Budget::default();
+
+        assert_eq!(
+            solve(input.as_bytes(), "input", &[1], &budget).unwrap(),
+            [0]
+        );
+        let Err(Error::Parse(error)) = solve(input.as_bytes(), "input", &[2], &budget) else {
+            panic!("streams part 2 with the intersection");
+        };
+        assert_eq!(error.location().line, 1);
+    }
+
+    #[test]
+    fn streaming_rejects_what_the_lists_in_memory_reject() {
+        let budget = Budget::default();
+        for input in ["3   4\n4\n", "3   4\nx   3\n", ""] {
+            let streamed = solve(input.as_bytes(), "input", &[1, 2], &budget);
+            match (streamed, Day1::solve_part1(input)) {
+                (Err(Error::Parse(_)), Err(Error::Parse(_))) => {}
+                (Err(Error::NoAnswer(_)), Err(Error::NoAnswer(_))) => {}
+                (streamed, in_memory) => {
+                    panic!("{:?} gives {:?} and {:?}", input, streamed, in_memory)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use aoc_common::{Answer, Grid, Override, Point, Solution, parse, trace};
+use itertools::Itertools;
+
+mod reference;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const INPUT_PATH: &'static str = "day-10/input.txt";
+
+    type Input = Grid<char>;
+
+    fn parse_with(input: &str, _overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        parse::expect_chars(
+            input,
+            input,
+            "0123456789.",
+            "a height from '0' to '9' or '.'",
+        )?;
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Answer> {
+        trace!("input {:?}", input);
+
+        let res = get_trail_heads(input)
+            .iter()
+            .flat_map(|head| calculate_score(input, *head, '0').into_iter().unique())
+            .count();
+
+        Ok(res.into())
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Answer> {
+        let res = get_trail_heads(input)
+            .iter()
+            .flat_map(|&head| calculate_score(input, head, '0'))
+            .count();
+
+        Ok(res.into())
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part2(input))
+    }
+}
+
+/// The summits reached from `position` at height `c`, once for every trail leading there.
+fn calculate_score(input: &Grid<char>, position: Point, c: char) -> Vec<Point> {
+    if !c.is_ascii_digit() {
+        return vec![];
+    }
+
+    if c.eq(&'9') {
+        return vec![position];
+    }
+
+    let incremented = ((c as u8) + 1) as char;
+
+    input
+        .neighbours_4(position)
+        .filter(|&next| input[next].eq(&incremented))
+        .flat_map(|next| calculate_score(input, next, incremented))
+        .collect()
+}
+
+fn get_trail_heads(input: &Grid<char>) -> Vec<Point> {
+    input
+        .iter()
+        .filter(|&item| item.1.eq(&'0'))
+        .map(|item| item.0)
+        .collect::<Vec<_>>()
+}
@@ -0,0 +1,5 @@
+use aoc_common::OrExit;
+
+fn main() {
+    aoc_common::run_day::<day_10::Day10>(["total score: ", "total score: "]).or_exit();
+}
@@ -0,0 +1,54 @@
+//! Slow but obviously correct solutions, checked against the fast ones by `aoc check`. They list
+//! every hiking trail, one step at a time.
+
+use std::collections::HashSet;
+
+use aoc_common::{Answer, Grid, Point, Solution};
+
+use crate::Day10;
+
+/// The number of distinct trailhead and summit pairs joined by a trail.
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    let map = Day10::parse(input)?;
+
+    let ends = trails(&map)
+        .iter()
+        .map(|trail| (trail[0], trail[9]))
+        .collect::<HashSet<_>>();
+    Ok(ends.len().into())
+}
+
+pub fn part2(input: &str) -> aoc_common::Result<Answer> {
+    let map = Day10::parse(input)?;
+
+    Ok(trails(&map).len().into())
+}
+
+/// Every path from a 0 to a 9 that climbs by exactly 1 with each step up, down, left or right.
+fn trails(map: &Grid<char>) -> Vec<Vec<Point>> {
+    let mut trails = map
+        .positions()
+        .filter(|&position| map[position] == '0')
+        .map(|position| vec![position])
+        .collect::<Vec<_>>();
+
+    for height in '1'..='9' {
+        let mut longer = vec![];
+        for trail in &trails {
+            let Point { x, y } = trail[trail.len() - 1];
+            let steps = [
+                Point::new(x + 1, y),
+                Point::new(x, y + 1),
+                Point::new(x.wrapping_sub(1), y),
+                Point::new(x, y.wrapping_sub(1)),
+            ];
+            for step in steps {
+                if map.get(step) == Some(&height) {
+                    longer.push([trail.clone(), vec![step]].concat());
+                }
+            }
+        }
+        trails = longer;
+    }
+    trails
+}
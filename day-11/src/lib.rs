@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, Header, Override, Param, Solution, debug, parse, trace};
+#[cfg(feature = "parallel")]
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+mod reference;
+
+pub struct Day11;
+
+/// The puzzle parameters, see [`Day11::PARAMS`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    /// How often the stones blink in part 1.
+    pub part1_blinks: u64,
+    /// How often the stones blink in part 2.
+    pub part2_blinks: u64,
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const INPUT_PATH: &'static str = "day-11/input.txt";
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "part1_blinks",
+            doc: "how often the stones blink in part 1",
+            default: "25",
+        },
+        Param {
+            name: "part2_blinks",
+            doc: "how often the stones blink in part 2",
+            default: "75",
+        },
+    ];
+
+    type Input = (Vec<u64>, Params);
+
+    fn parse_with(input: &str, overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        let (header, stones) = Header::split(input, Self::PARAMS, overrides)?;
+        let params = Params {
+            part1_blinks: header.number("part1_blinks")?,
+            part2_blinks: header.number("part2_blinks")?,
+        };
+        let stones = stones
+            .split_whitespace()
+            .map(|stone| parse::number(input, stone))
+            .collect::<Result<_, _>>()?;
+        Ok((stones, params))
+    }
+
+    fn part1((input, params): &Self::Input) -> aoc_common::Result<Answer> {
+        let res = recurse(input.clone(), params.part1_blinks);
+        Ok(res.len().into())
+    }
+
+    fn part2((input, params): &Self::Input) -> aoc_common::Result<Answer> {
+        // Each thread keeps its own memo, the stones share most of their descendants anyway.
+        #[cfg(feature = "parallel")]
+        let res: u64 = input
+            .par_iter()
+            .map_init(HashMap::new, |count_map, &item| {
+                recurse_dynamic(count_map, item, params.part2_blinks)
+            })
+            .sum();
+
+        #[cfg(not(feature = "parallel"))]
+        let res: u64 = {
+            let mut count_map: HashMap<(u64, u64), u64> = HashMap::new();
+            input
+                .iter()
+                .map(|&item| recurse_dynamic(&mut count_map, item, params.part2_blinks))
+                .sum()
+        };
+
+        Ok(res.into())
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+        reference::part2(input)
+    }
+}
+
+fn recurse(input: Vec<u64>, depth: u64) -> Vec<u64> {
+    trace!("{} blinks left: {:?}", depth, input);
+    if depth == 0 {
+        return input;
+    }
+    #[cfg(feature = "parallel")]
+    let next = input
+        .par_iter()
+        .flat_map_iter(|&item| do_iteration(item))
+        .collect();
+    #[cfg(not(feature = "parallel"))]
+    let next = input.iter().flat_map(|&item| do_iteration(item)).collect();
+
+    recurse(next, depth - 1)
+}
+
+fn recurse_dynamic(count_map: &mut HashMap<(u64, u64), u64>, input: u64, depth: u64) -> u64 {
+    trace!("{} blinks left: {}", depth, input);
+
+    if depth == 0 {
+        count_map.insert((input, depth), 1);
+        return 1;
+    }
+
+    let memoized_count = count_map.get(&(input, depth));
+    let real_count = match memoized_count {
+        Some(&from_map) => from_map,
+        None => {
+            let count_recursed = do_iteration(input)
+                .iter()
+                .map(|&next_val| recurse_dynamic(count_map, next_val, depth - 1))
+                .sum();
+            count_map.insert((input, depth), count_recursed);
+            count_recursed
+        }
+    };
+
+    real_count
+}
+
+fn do_iteration(input: u64) -> Vec<u64> {
+    match input {
+        0 => vec![1],
+        n if n.to_string().len() % 2 == 0 => split(n),
+        _ => vec![input * 2024],
+    }
+}
+
+fn split(input: u64) -> Vec<u64> {
+    let as_str = input.to_string();
+    let (first, sec) = as_str.split_at(as_str.len() / 2);
+    let res = [first, sec]
+        .iter()
+        .map(|num_str| num_str.parse::<u64>())
+        .collect::<Result<Vec<_>, _>>();
+
+    if let Err(parse_err) = &res {
+        debug!("parse error: {:?}", parse_err);
+    }
+
+    res.iter().flatten().copied().collect::<Vec<_>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::testing::answer;
+
+    use super::*;
+
+    #[test]
+    fn blinks_are_read_from_the_header() {
+        assert_eq!(answer(Day11::solve_part1, "125 17\n"), "55312");
+        assert_eq!(answer(Day11::solve_part1, "part1_blinks=6\n125 17\n"), "22");
+        assert_eq!(answer(Day11::solve_part2, "part2_blinks=6\n125 17\n"), "22");
+    }
+}
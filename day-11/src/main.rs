@@ -0,0 +1,5 @@
+use aoc_common::OrExit;
+
+fn main() {
+    aoc_common::run_day::<day_11::Day11>(["Number of stones: ", "Number of stones: "]).or_exit();
+}
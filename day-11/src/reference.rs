@@ -0,0 +1,50 @@
+//! A slow but obviously correct solution, checked against the fast one by `aoc check`. It keeps
+//! every stone as its engraved digits, so part 2 is only in reach with far fewer than its 75
+//! blinks, as on the generated inputs.
+
+use aoc_common::{Answer, Solution};
+
+use crate::Day11;
+
+/// The most blinks part 2 lists the stones for.
+const MAX_BLINKS: u64 = 30;
+
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    let (stones, params) = Day11::parse(input)?;
+    Ok(count_after(&stones, params.part1_blinks).into())
+}
+
+/// `None` for more blinks than [`MAX_BLINKS`].
+pub fn part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+    let (stones, params) = match Day11::parse(input) {
+        Ok(parsed) => parsed,
+        Err(error) => return Some(Err(error)),
+    };
+    (params.part2_blinks <= MAX_BLINKS)
+        .then(|| Ok(count_after(&stones, params.part2_blinks).into()))
+}
+
+/// The number of stones after `blinks` blinks.
+fn count_after(stones: &[u64], blinks: u64) -> usize {
+    let mut stones = stones.iter().map(u64::to_string).collect::<Vec<_>>();
+
+    for _ in 0..blinks {
+        let mut next = vec![];
+        for stone in &stones {
+            if stone == "0" {
+                next.push("1".to_string());
+            } else if stone.len() % 2 == 0 {
+                let (left, right) = stone.split_at(stone.len() / 2);
+                next.push(left.to_string());
+                // Dropping the leading zeros, but keeping a lone 0.
+                let right = right.trim_start_matches('0');
+                next.push(if right.is_empty() { "0" } else { right }.to_string());
+            } else {
+                let number = stone.parse::<u128>().expect("stones are numbers");
+                next.push((number * 2024).to_string());
+            }
+        }
+        stones = next;
+    }
+    stones.len()
+}
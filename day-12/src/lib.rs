@@ -0,0 +1,182 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{Answer, Direction, Grid, Override, Point, Solution, trace};
+use itertools::Itertools;
+
+mod reference;
+
+/// Plant type, perimeter and plots of a region.
+type Region = (char, usize, HashSet<Point>);
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const INPUT_PATH: &'static str = "day-12/input.txt";
+
+    type Input = Grid<char>;
+
+    fn parse_with(input: &str, _overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Answer> {
+        let mut processed: HashMap<Point, Region> = HashMap::new();
+
+        for (coords, &c) in input.iter() {
+            if processed.contains_key(&coords) {
+                continue;
+            }
+
+            let mut visited = HashSet::new();
+            let perimeter = count_perimeter(&mut visited, (coords, c), input);
+            for &visited_coord in &visited {
+                processed.insert(visited_coord, (c, perimeter, visited.clone()));
+            }
+        }
+
+        trace!("processed {:?}", processed);
+
+        let res: usize = processed
+            .iter()
+            .unique_by(|item| item.1.2.iter().copied().collect::<Vec<_>>())
+            .map(|item| item.1.1 * item.1.2.len())
+            .sum();
+
+        Ok(res.into())
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Answer> {
+        let (x_length, y_length) = (input.width(), input.height());
+
+        let mut regions: HashMap<Point, HashSet<Point>> = HashMap::new();
+
+        for (coords, &c) in input.iter() {
+            if regions.contains_key(&coords) {
+                continue;
+            }
+
+            let mut visited = HashSet::new();
+            count_perimeter(&mut visited, (coords, c), input);
+            for &visited_coord in &visited {
+                regions.insert(visited_coord, visited.clone());
+            }
+        }
+        let mut sides: HashMap<Point, u64> = HashMap::new();
+        let mut previous_top_side = false;
+        let mut previous_bottom_side = false;
+        for y_ind in 0..y_length {
+            for x_ind in 0..x_length {
+                let position = Point::new(x_ind, y_ind);
+                let tile = (position, input[position]);
+
+                let is_up_a_side = same_region_neighbour(input, tile, Direction::Up).is_none();
+                let is_down_a_side = same_region_neighbour(input, tile, Direction::Down).is_none();
+
+                let sides_to_add = (!previous_top_side && is_up_a_side) as u64
+                    + (!previous_bottom_side && is_down_a_side) as u64;
+
+                sides
+                    .entry(position)
+                    .and_modify(|old| *old += sides_to_add)
+                    .or_insert(sides_to_add);
+
+                trace!(
+                    "{:?} {:?}: up side {} (previous {}), down side {} (previous {}), adds {}",
+                    tile.0,
+                    tile.1,
+                    is_up_a_side,
+                    previous_top_side,
+                    is_down_a_side,
+                    previous_bottom_side,
+                    sides_to_add
+                );
+
+                let next_in_line = same_region_neighbour(input, tile, Direction::Right);
+                previous_top_side = is_up_a_side && next_in_line.is_some();
+                previous_bottom_side = is_down_a_side && next_in_line.is_some();
+            }
+            previous_top_side = false;
+            previous_bottom_side = false;
+        }
+
+        let mut previous_left_side = false;
+        let mut previous_right_side = false;
+        for x_ind in 0..x_length {
+            for y_ind in 0..y_length {
+                let position = Point::new(x_ind, y_ind);
+                let tile = (position, input[position]);
+
+                let is_left_a_side = same_region_neighbour(input, tile, Direction::Left).is_none();
+                let is_right_a_side =
+                    same_region_neighbour(input, tile, Direction::Right).is_none();
+
+                let sides_to_add = (!previous_left_side && is_left_a_side) as u64
+                    + (!previous_right_side && is_right_a_side) as u64;
+
+                sides
+                    .entry(position)
+                    .and_modify(|old| *old += sides_to_add)
+                    .or_insert(sides_to_add);
+
+                let next_in_line = same_region_neighbour(input, tile, Direction::Down);
+
+                previous_left_side = is_left_a_side && next_in_line.is_some();
+                previous_right_side = is_right_a_side && next_in_line.is_some();
+            }
+            previous_left_side = false;
+            previous_right_side = false;
+        }
+
+        let res: u64 = regions
+            .iter()
+            .unique_by(|item| item.1.iter().copied().collect::<Vec<_>>())
+            .map(|item| {
+                let side_count: u64 = item.1.iter().flat_map(|coord| sides.get(coord)).sum();
+                side_count * (item.1.len() as u64)
+            })
+            .sum();
+
+        Ok(res.into())
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part2(input))
+    }
+}
+
+fn count_perimeter(
+    visited: &mut HashSet<Point>,
+    (cur_pos, c): (Point, char),
+    coord_map: &Grid<char>,
+) -> usize {
+    if visited.contains(&cur_pos) {
+        return 0;
+    }
+
+    visited.insert(cur_pos);
+
+    Direction::ALL
+        .into_iter()
+        .map(|dir| {
+            same_region_neighbour(coord_map, (cur_pos, c), dir)
+                .map(|next_coords| count_perimeter(visited, (next_coords, c), coord_map))
+                .unwrap_or(1)
+        })
+        .sum()
+}
+
+/// The neighbour of `cur_pos` in `dir`, if it grows the same plant `c`.
+fn same_region_neighbour(
+    coord_map: &Grid<char>,
+    (cur_pos, c): (Point, char),
+    dir: Direction,
+) -> Option<Point> {
+    coord_map
+        .step(cur_pos, dir)
+        .filter(|&next_coords| coord_map[next_coords].eq(&c))
+}
@@ -0,0 +1,5 @@
+use aoc_common::OrExit;
+
+fn main() {
+    aoc_common::run_day::<day_12::Day12>(["total price: ", "total price: "]).or_exit();
+}
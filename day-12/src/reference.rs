@@ -0,0 +1,83 @@
+//! Slow but obviously correct solutions, checked against the fast ones by `aoc check`. They
+//! price each region by looking at the fence on every side of every plot.
+
+use std::collections::HashSet;
+
+use aoc_common::{Answer, Grid, Solution};
+
+use crate::Day12;
+
+/// The four sides of a plot, as offsets to the neighbour across them.
+const SIDES: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Every side of a plot that faces another region or the edge of the map is one unit of fence.
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    let map = Day12::parse(input)?;
+
+    let price = regions(&map)
+        .iter()
+        .map(|region| region.len() * fences(region).len())
+        .sum::<usize>();
+    Ok(price.into())
+}
+
+/// A unit of fence starts a new side unless the plot before it along the side has the same
+/// fence.
+pub fn part2(input: &str) -> aoc_common::Result<Answer> {
+    let map = Day12::parse(input)?;
+
+    let price = regions(&map)
+        .iter()
+        .map(|region| {
+            let fences = fences(region);
+            let sides = fences
+                .iter()
+                .filter(|&&((x, y), (dx, dy))| {
+                    // Along the side is a quarter turn from across it.
+                    let before = (x + dy, y - dx);
+                    !fences.contains(&(before, (dx, dy)))
+                })
+                .count();
+            region.len() * sides
+        })
+        .sum::<usize>();
+    Ok(price.into())
+}
+
+/// The plots of each region, found by growing it one neighbour at a time.
+fn regions(map: &Grid<char>) -> Vec<HashSet<(i64, i64)>> {
+    let mut regions: Vec<HashSet<(i64, i64)>> = vec![];
+
+    for position in map.positions() {
+        let plot = (position.x as i64, position.y as i64);
+        if regions.iter().any(|region| region.contains(&plot)) {
+            continue;
+        }
+
+        let plant = map[position];
+        let mut region = HashSet::from([plot]);
+        let mut grown = true;
+        while grown {
+            grown = false;
+            for (x, y) in region.clone() {
+                for (dx, dy) in SIDES {
+                    let neighbour = (x + dx, y + dy);
+                    if map.get_signed(neighbour) == Some(&plant) && region.insert(neighbour) {
+                        grown = true;
+                    }
+                }
+            }
+        }
+        regions.push(region);
+    }
+    regions
+}
+
+/// The plot and the side of it of every unit of fence around `region`.
+fn fences(region: &HashSet<(i64, i64)>) -> HashSet<((i64, i64), (i64, i64))> {
+    region
+        .iter()
+        .flat_map(|&(x, y)| SIDES.map(|side| ((x, y), side)))
+        .filter(|&((x, y), (dx, dy))| !region.contains(&(x + dx, y + dy)))
+        .collect()
+}
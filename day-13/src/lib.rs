@@ -0,0 +1,373 @@
+use aoc_common::{Answer, Header, Override, Param, ParseError, Solution, parse, trace};
+use regex::Regex;
+
+mod reference;
+
+/// Button A x/y, button B x/y and prize x/y.
+type Machine = (u64, u64, u64, u64, u64, u64);
+
+/// How often part 1 may press each button.
+const MAX_PRESSES: usize = 100;
+
+pub struct Day13;
+
+/// The puzzle parameters, see [`Day13::PARAMS`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    /// Part 2's prizes are this much further away on both axes.
+    pub prize_offset: i64,
+}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const INPUT_PATH: &'static str = "day-13/input.txt";
+    const PARAMS: &'static [Param] = &[Param {
+        name: "prize_offset",
+        doc: "how much further away the prizes are on both axes in part 2",
+        default: "10000000000000",
+    }];
+
+    type Input = (Vec<Machine>, Params);
+
+    fn parse_with(input: &str, overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        let (header, machines) = Header::split(input, Self::PARAMS, overrides)?;
+        let params = Params {
+            prize_offset: header.number("prize_offset")?,
+        };
+        Ok((regex_parse(input, machines)?, params))
+    }
+
+    fn part1((input, _): &Self::Input) -> aoc_common::Result<Answer> {
+        trace!("machines {:?}", input);
+
+        let res: u64 = input
+            .iter()
+            .flat_map(|&a_b_price| get_solution(a_b_price))
+            .sum();
+
+        Ok(res.into())
+    }
+
+    fn part2((input, params): &Self::Input) -> aoc_common::Result<Answer> {
+        let offset = i128::from(params.prize_offset);
+        let res: i128 = input
+            .iter()
+            .flat_map(|&(a_x, a_y, b_x, b_y, price_x, price_y)| {
+                solve_machine((
+                    a_x.into(),
+                    a_y.into(),
+                    b_x.into(),
+                    b_y.into(),
+                    i128::from(price_x) + offset,
+                    i128::from(price_y) + offset,
+                ))
+            })
+            .sum();
+
+        Ok(res.into())
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+        reference::part2(input)
+    }
+}
+
+/// The fewest tokens that win the prize with each button pressed at most 100 times, as part 1
+/// allows.
+fn get_solution((a_x, a_y, b_x, b_y, price_x, price_y): Machine) -> Option<u64> {
+    let mut solutions: Vec<(u64, u64)> = vec![];
+
+    let a_x_range = (0..=price_x).step_by(a_x.try_into().unwrap());
+    let a_y_range = (0..=price_y).step_by(a_y.try_into().unwrap());
+
+    for (a_button_presses, (a_x_part, a_y_part)) in
+        a_x_range.zip(a_y_range).enumerate().take(MAX_PRESSES + 1)
+    {
+        let remaining_x = price_x - a_x_part;
+        let remaining_y = price_y - a_y_part;
+
+        if remaining_x % b_x != 0 || remaining_y % b_y != 0 {
+            continue;
+        }
+
+        let b_button_presses_by_x = remaining_x / b_x;
+        let b_button_presses_by_y = remaining_y / b_y;
+
+        if b_button_presses_by_x == b_button_presses_by_y
+            && b_button_presses_by_x <= MAX_PRESSES as u64
+        {
+            solutions.push((a_button_presses.try_into().unwrap(), b_button_presses_by_x));
+        }
+    }
+
+    solutions
+        .iter()
+        .map(|&(a_presses, b_presses)| a_presses * 3 + b_presses)
+        .min()
+}
+
+/// The tokens the only way of winning the prize costs, in i128 so that no product of a far away
+/// prize overflows. `None` when the presses this takes are not whole or not positive. Buttons
+/// that move the claw along the same line leave many ways, see [`cheapest_on_line`].
+fn solve_machine(
+    (a_x, a_y, b_x, b_y, price_x, price_y): (i128, i128, i128, i128, i128, i128),
+) -> Option<i128> {
+    if b_y * a_x - b_x * a_y == 0 {
+        // The prize has to be on the line too, then the presses that reach it on the x axis
+        // reach it on the y axis as well.
+        if price_y * a_x != price_x * a_y {
+            return None;
+        }
+        return cheapest_on_line(a_x, b_x, price_x);
+    }
+
+    let b_presses_is_integer =
+        (price_y * a_x - price_x * a_y).checked_rem(b_y * a_x - b_x * a_y) == Some(0);
+
+    if !b_presses_is_integer {
+        return None;
+    }
+
+    let b_presses = (price_y * a_x - price_x * a_y) / (b_y * a_x - b_x * a_y);
+
+    let a_presses_is_integer = (price_y - b_presses * b_y) % a_y == 0;
+
+    if !a_presses_is_integer {
+        return None;
+    }
+
+    let a_presses = (price_y - b_presses * b_y) / a_y;
+
+    if a_presses < 0 || b_presses < 0 {
+        return None;
+    }
+
+    Some(a_presses * 3 + b_presses)
+}
+
+/// The fewest tokens for presses of A moving `a` and of B moving `b` that add up to `price`, both
+/// larger than 0, with A costing 3 tokens and B 1.
+///
+/// The whole solutions are `a_presses = a_0 + k * b / g` and `b_presses = b_0 - k * a / g` for
+/// one solution `(a_0, b_0)` and the greatest common divisor `g` of the movements. The cost
+/// changes by the same amount with every step of `k`, so the cheapest presses are at one end of
+/// the steps that keep both numbers of presses from going negative.
+fn cheapest_on_line(a: i128, b: i128, price: i128) -> Option<i128> {
+    let (g, x, y) = extended_gcd(a, b);
+    if price % g != 0 {
+        return None;
+    }
+    let (a_0, b_0) = (x * (price / g), y * (price / g));
+    let (a_step, b_step) = (b / g, a / g);
+
+    // a_0 + k * a_step >= 0 and b_0 - k * b_step >= 0.
+    let lowest = -(a_0.div_euclid(a_step));
+    let lowest = if a_0 + lowest * a_step < 0 {
+        lowest + 1
+    } else {
+        lowest
+    };
+    let highest = b_0.div_euclid(b_step);
+    if lowest > highest {
+        return None;
+    }
+
+    let cost = |k: i128| 3 * (a_0 + k * a_step) + (b_0 - k * b_step);
+    Some(cost(lowest).min(cost(highest)))
+}
+
+/// The greatest common divisor `g` of `a` and `b`, both larger than 0, and `x` and `y` with
+/// `a * x + b * y = g`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a, 1, 0);
+    }
+    let (g, x, y) = extended_gcd(b, a % b);
+    (g, y, x - (a / b) * y)
+}
+
+/// `machines` is the part of `input` after the header.
+fn regex_parse(input: &str, machines: &str) -> Result<Vec<Machine>, ParseError> {
+    let button_a = Regex::new(r"^Button A: X\+(\d+), Y\+(\d+)$").unwrap();
+    let button_b = Regex::new(r"^Button B: X\+(\d+), Y\+(\d+)$").unwrap();
+    let prize = Regex::new(r"^Prize: X=(\d+), Y=(\d+)$").unwrap();
+
+    let mut parsed: Vec<Machine> = vec![];
+    let mut lines = machines
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .peekable();
+
+    while lines.peek().is_some() {
+        let [a_x, a_y] = regex_parse_line(
+            input,
+            lines.next(),
+            &button_a,
+            "\"Button A: X+<number>, Y+<number>\"",
+        )?;
+        let [b_x, b_y] = regex_parse_line(
+            input,
+            lines.next(),
+            &button_b,
+            "\"Button B: X+<number>, Y+<number>\"",
+        )?;
+        let [price_x, price_y] = regex_parse_line(
+            input,
+            lines.next(),
+            &prize,
+            "\"Prize: X=<number>, Y=<number>\"",
+        )?;
+
+        parsed.push((
+            button_movement(input, a_x)?,
+            button_movement(input, a_y)?,
+            button_movement(input, b_x)?,
+            button_movement(input, b_y)?,
+            parse::number::<u64>(input, price_x)?,
+            parse::number::<u64>(input, price_y)?,
+        ));
+    }
+
+    Ok(parsed)
+}
+
+fn regex_parse_line<'a>(
+    input: &str,
+    line: Option<&'a str>,
+    regex: &Regex,
+    expected: &str,
+) -> Result<[&'a str; 2], ParseError> {
+    let line = line.ok_or_else(|| ParseError::unexpected_end(input, expected))?;
+    let captures = regex
+        .captures(line)
+        .ok_or_else(|| ParseError::unexpected(input, line, expected))?;
+    Ok(captures.extract().1)
+}
+
+/// Buttons that do not move the claw would make the search divide by zero.
+fn button_movement(input: &str, token: &str) -> Result<u64, ParseError> {
+    match parse::number::<u64>(input, token)? {
+        0 => Err(ParseError::unexpected(
+            input,
+            token,
+            "a button movement larger than 0",
+        )),
+        movement => Ok(movement),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Rng;
+
+    use super::*;
+
+    #[test]
+    fn prizes_that_take_negative_presses_cannot_be_won() {
+        // 298 presses of A and -416 of B.
+        let input = "prize_offset=8218\n\
+                     Button A: X+52, Y+64\nButton B: X+15, Y+23\nPrize: X=1038, Y=1286\n";
+        assert_eq!(Day13::solve_part2(input).unwrap().to_string(), "0");
+    }
+
+    #[test]
+    fn part_1_presses_each_button_at_most_100_times() {
+        // 103 presses of A and 72 of B.
+        let input = "Button A: X+14, Y+12\nButton B: X+90, Y+83\nPrize: X=7922, Y=7212\n";
+        assert_eq!(Day13::solve_part1(input).unwrap().to_string(), "0");
+        // 100 presses of A and 72 of B.
+        let input = "Button A: X+14, Y+12\nButton B: X+90, Y+83\nPrize: X=7880, Y=7176\n";
+        assert_eq!(Day13::solve_part1(input).unwrap().to_string(), "372");
+    }
+
+    #[test]
+    fn buttons_along_the_same_line_take_the_cheapest_presses() {
+        let input = "prize_offset=0\n\
+                     Button A: X+1, Y+1\nButton B: X+2, Y+2\nPrize: X=4, Y=4\n";
+        assert_eq!(Day13::solve_part1(input).unwrap().to_string(), "2");
+        assert_eq!(Day13::solve_part2(input).unwrap().to_string(), "2");
+
+        // The cheaper of pressing only A or only B, or a mix that makes up for a remainder.
+        let cases = [
+            (
+                "Button A: X+7, Y+14\nButton B: X+6, Y+12\nPrize: X=42, Y=84\n",
+                "7",
+            ),
+            (
+                "Button A: X+7, Y+14\nButton B: X+1, Y+2\nPrize: X=70, Y=140\n",
+                "30",
+            ),
+            (
+                "Button A: X+5, Y+5\nButton B: X+3, Y+3\nPrize: X=14, Y=14\n",
+                "6",
+            ),
+            (
+                "Button A: X+4, Y+4\nButton B: X+6, Y+6\nPrize: X=5, Y=5\n",
+                "0",
+            ),
+            (
+                "Button A: X+1, Y+1\nButton B: X+2, Y+2\nPrize: X=4, Y=5\n",
+                "0",
+            ),
+        ];
+        for (machine, tokens) in cases {
+            let input = format!("prize_offset=0\n{}", machine);
+            assert_eq!(
+                Day13::solve_part1(&input).unwrap().to_string(),
+                tokens,
+                "{}",
+                machine
+            );
+            assert_eq!(
+                Day13::solve_part2(&input).unwrap().to_string(),
+                tokens,
+                "{}",
+                machine
+            );
+        }
+
+        // Far away, where trying the presses one by one is out of reach.
+        let input = "Button A: X+3, Y+3\nButton B: X+2, Y+2\nPrize: X=0, Y=0\n";
+        assert_eq!(
+            Day13::solve_part2(input).unwrap().to_string(),
+            "5000000000000"
+        );
+    }
+
+    #[test]
+    fn buttons_along_the_same_line_agree_with_the_reference() {
+        for seed in 0..100 {
+            let mut rng = Rng::new(seed);
+            let slope = rng.between(1, 3);
+            let (a, b, prize) = (rng.between(1, 9), rng.between(1, 9), rng.below(200));
+            let input = format!(
+                "prize_offset={}\nButton A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                rng.below(50),
+                a,
+                a * slope,
+                b,
+                b * slope,
+                prize,
+                prize * slope
+            );
+
+            assert_eq!(
+                Day13::solve_part2(&input).unwrap(),
+                reference::part2(&input).unwrap().unwrap(),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn far_away_prizes_do_not_overflow() {
+        let input = "prize_offset=9223372036854775807\n\
+                     Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n";
+        assert!(Day13::solve_part2(input).is_ok());
+    }
+}
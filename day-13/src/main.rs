@@ -0,0 +1,5 @@
+use aoc_common::OrExit;
+
+fn main() {
+    aoc_common::run_day::<day_13::Day13>(["fewest tokens: ", "fewest tokens: "]).or_exit();
+}
@@ -0,0 +1,57 @@
+//! A slow but obviously correct solution, checked against the fast one by `aoc check`. Part 2
+//! tries the presses of button A one by one, so it is only in reach with a far smaller prize
+//! offset than the puzzle's, as on the generated inputs.
+
+use aoc_common::{Answer, Solution};
+
+use crate::Day13;
+
+/// The largest prize offset part 2 tries the presses for.
+const MAX_OFFSET: i64 = 100_000;
+
+/// Tries every number of presses up to 100 of both buttons, A costing 3 tokens and B 1.
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    let (machines, _) = Day13::parse(input)?;
+
+    let mut tokens = 0;
+    for (a_x, a_y, b_x, b_y, prize_x, prize_y) in machines {
+        // Wide enough that no product overflows.
+        let [a_x, a_y, b_x, b_y, prize_x, prize_y] =
+            [a_x, a_y, b_x, b_y, prize_x, prize_y].map(i128::from);
+        let cheapest = (0..=100i128)
+            .flat_map(|a| (0..=100i128).map(move |b| (a, b)))
+            .filter(|&(a, b)| a * a_x + b * b_x == prize_x && a * a_y + b * b_y == prize_y)
+            .map(|(a, b)| 3 * a + b)
+            .min();
+        tokens += cheapest.unwrap_or(0);
+    }
+    Ok(tokens.into())
+}
+
+/// Tries every number of presses of button A that does not overshoot the prize, and presses
+/// button B for the rest of the way when it fits. `None` for offsets beyond [`MAX_OFFSET`].
+pub fn part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+    let (machines, params) = match Day13::parse(input) {
+        Ok(parsed) => parsed,
+        Err(error) => return Some(Err(error)),
+    };
+    if params.prize_offset > MAX_OFFSET {
+        return None;
+    }
+
+    let mut tokens = 0;
+    for (a_x, a_y, b_x, b_y, prize_x, prize_y) in machines {
+        let [a_x, a_y, b_x, b_y] = [a_x, a_y, b_x, b_y].map(i128::from);
+        let offset = i128::from(params.prize_offset);
+        let (prize_x, prize_y) = (i128::from(prize_x) + offset, i128::from(prize_y) + offset);
+        let cheapest = (0..=prize_x.max(0) / a_x)
+            .filter_map(|a| {
+                let (rest_x, rest_y) = (prize_x - a * a_x, prize_y - a * a_y);
+                let b = rest_x / b_x;
+                (b >= 0 && rest_x % b_x == 0 && b * b_y == rest_y).then_some(3 * a + b)
+            })
+            .min();
+        tokens += cheapest.unwrap_or(0);
+    }
+    Some(Ok(tokens.into()))
+}
@@ -0,0 +1,348 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use aoc_common::{
+    Answer, Error, Header, Override, Param, ParseError, Solution, debug, drawing, params, parse,
+    trace,
+};
+use itertools::Itertools;
+use regex::Regex;
+
+mod reference;
+
+pub struct Day14;
+
+/// The puzzle parameters other than the size of the area, see [`Day14::PARAMS`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// After how many seconds part 1 counts the robots.
+    pub part1_seconds: u32,
+    /// The columns and rows where the Christmas tree shows up.
+    pub tree_columns: RangeInclusive<usize>,
+    pub tree_rows: RangeInclusive<usize>,
+    /// How many robots in the tree's columns and rows make a tree.
+    pub tree_robots: usize,
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const INPUT_PATH: &'static str = "day-14/input.txt";
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "robot_area_size",
+            doc: "the width and height of the area, both odd",
+            default: "101,103",
+        },
+        Param {
+            name: "part1_seconds",
+            doc: "after how many seconds part 1 counts the robots",
+            default: "100",
+        },
+        Param {
+            name: "tree_columns",
+            doc: "the columns where part 2 looks for the Christmas tree, <min>..=<max> or <min>..",
+            default: "45..=79",
+        },
+        Param {
+            name: "tree_rows",
+            doc: "the rows where part 2 looks for the Christmas tree, <min>..=<max> or <min>..",
+            default: "45..",
+        },
+        Param {
+            name: "tree_robots",
+            doc: "how many robots in the tree's columns and rows make a tree",
+            default: "300",
+        },
+    ];
+
+    type Input = (RobotArea, Params);
+
+    fn parse_with(input: &str, overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        let (header, robots) = Header::split(input, Self::PARAMS, overrides)?;
+        let dimensions = header.get_with("robot_area_size", parse_dimensions)?;
+        let params = Params {
+            part1_seconds: header.number("part1_seconds")?,
+            tree_columns: header.get_with("tree_columns", params::range)?,
+            tree_rows: header.get_with("tree_rows", params::range)?,
+            tree_robots: header.number("tree_robots")?,
+        };
+        let robot_data = parse_robots(input, robots)?;
+        debug!("dimensions {:?}, {:?}", dimensions, params);
+        trace!("robot_data {:?}", robot_data);
+        Ok((
+            RobotArea {
+                robot_data,
+                dimensions,
+            },
+            params,
+        ))
+    }
+
+    fn part1((robot_area, params): &Self::Input) -> aoc_common::Result<Answer> {
+        let after_n_steps = robot_area.do_n_steps(params.part1_seconds.into());
+        #[cfg(feature = "visualize")]
+        trace!("after_n_steps\n{}", after_n_steps.render());
+
+        Ok(after_n_steps.mul_quadrants().into())
+    }
+
+    fn part2((robot_area, params): &Self::Input) -> aoc_common::Result<Answer> {
+        // Every robot is back where it started after width * height seconds, which only fits a
+        // u64 for the largest areas.
+        let period = u64::from(robot_area.dimensions.0) * u64::from(robot_area.dimensions.1);
+        let seconds_until_tree = (0..period)
+            .find_map(|n| robot_area.do_n_steps(n).find_xmas_tree(n, params))
+            .ok_or_else(|| {
+                Error::NoAnswer(format!(
+                    "no Christmas tree shows up within {} seconds",
+                    period
+                ))
+            })?;
+
+        Ok(seconds_until_tree.into())
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part2(input))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RobotArea {
+    robot_data: Vec<RobotData>,
+    dimensions: (u32, u32),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct RobotData {
+    pos_x: i64,
+    pos_y: i64,
+    vel_x: i64,
+    vel_y: i64,
+}
+
+impl From<(u32, u32, i64, i64)> for RobotData {
+    fn from(data: (u32, u32, i64, i64)) -> Self {
+        Self {
+            pos_x: data.0.into(),
+            pos_y: data.1.into(),
+            vel_x: data.2,
+            vel_y: data.3,
+        }
+    }
+}
+
+/// The area in the puzzle input format, the size line first.
+impl fmt::Display for RobotArea {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "robot_area_size={},{}",
+            self.dimensions.0, self.dimensions.1
+        )?;
+        for robot in &self.robot_data {
+            writeln!(
+                f,
+                "p={},{} v={},{}",
+                robot.pos_x, robot.pos_y, robot.vel_x, robot.vel_y
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl RobotArea {
+    fn do_n_steps(&self, n: u64) -> RobotArea {
+        // In i128, so that no velocity times any number of seconds overflows.
+        let step = |position: i64, velocity: i64, size: u32| {
+            let next = (i128::from(velocity) * i128::from(n) + i128::from(position))
+                .rem_euclid(i128::from(size));
+            next as i64
+        };
+        let next_data = self
+            .robot_data
+            .iter()
+            .map(|&data| RobotData {
+                pos_x: step(data.pos_x, data.vel_x, self.dimensions.0),
+                pos_y: step(data.pos_y, data.vel_y, self.dimensions.1),
+                ..data
+            })
+            .sorted_by_key(|data| (data.pos_y, data.pos_x))
+            .collect::<Vec<_>>();
+
+        RobotArea {
+            robot_data: next_data,
+            ..self.clone()
+        }
+    }
+}
+
+impl RobotArea {
+    fn find_xmas_tree(&self, n: u64, params: &Params) -> Option<u64> {
+        let critical_area_robot_count =
+            self.count_robots_within(&params.tree_columns, &params.tree_rows);
+
+        if critical_area_robot_count < params.tree_robots {
+            return None;
+        }
+
+        debug!(
+            "n: {} critical_area_robot_count: {}{}",
+            n,
+            critical_area_robot_count,
+            drawing!(self.render())
+        );
+
+        Some(n)
+    }
+}
+
+#[cfg(feature = "visualize")]
+impl RobotArea {
+    /// The robot counts per tile, `.` for none.
+    fn render(&self) -> String {
+        let grouped = self
+            .robot_data
+            .iter()
+            .into_group_map_by(|&&data| (data.pos_x, data.pos_y));
+        let mut formatted = String::new();
+        for y in 0..self.dimensions.1 {
+            for x in 0..self.dimensions.0 {
+                let char_to_print = grouped
+                    .get(&(x.into(), y.into()))
+                    .map(|robots| (robots.len() % 10).to_string().chars().nth(0).unwrap())
+                    .unwrap_or('.');
+                formatted.push(char_to_print);
+            }
+            formatted.push('\n');
+        }
+        formatted
+    }
+}
+
+impl RobotArea {
+    /// The robots in the given columns and rows, ends included.
+    fn count_robots_within(
+        &self,
+        columns: &RangeInclusive<usize>,
+        rows: &RangeInclusive<usize>,
+    ) -> usize {
+        let within = |range: &RangeInclusive<usize>, position: i64| {
+            usize::try_from(position).is_ok_and(|position| range.contains(&position))
+        };
+        self.robot_data
+            .iter()
+            .filter(|data| within(columns, data.pos_x) && within(rows, data.pos_y))
+            .count()
+    }
+
+    fn count_robots(&self, from_x: u32, until_x: u32, from_y: u32, until_y: u32) -> usize {
+        trace!(
+            "counting robots in x {}..{}, y {}..{}",
+            from_x, until_x, from_y, until_y
+        );
+        let sum = self
+            .robot_data
+            .iter()
+            .filter(|&&data| {
+                let hit = data.pos_x >= from_x.into()
+                    && data.pos_x < until_x.into()
+                    && data.pos_y >= from_y.into()
+                    && data.pos_y < until_y.into();
+                trace!("data: {:?}, hit: {}", data, hit);
+                hit
+            })
+            .count();
+        sum
+    }
+
+    fn mul_quadrants(&self) -> usize {
+        self.count_robots(0, self.dimensions.0 / 2, 0, self.dimensions.1 / 2)
+            * self.count_robots(
+                (self.dimensions.0 / 2) + 1,
+                self.dimensions.0,
+                0,
+                self.dimensions.1 / 2,
+            )
+            * self.count_robots(
+                0,
+                self.dimensions.0 / 2,
+                (self.dimensions.1 / 2) + 1,
+                self.dimensions.1,
+            )
+            * self.count_robots(
+                (self.dimensions.0 / 2) + 1,
+                self.dimensions.0,
+                (self.dimensions.1 / 2) + 1,
+                self.dimensions.1,
+            )
+    }
+}
+
+/// `robots` is the part of `input` after the header.
+fn parse_robots(input: &str, robots: &str) -> Result<Vec<RobotData>, ParseError> {
+    let robot_regex =
+        Regex::new(r"^p=(?<p_x>\d+),(?<p_y>\d+) v=(?<v_x>-?\d+),(?<v_y>-?\d+)$").unwrap();
+
+    let mut robot_data: Vec<RobotData> = vec![];
+    for line in robots.lines().filter(|line| !line.trim().is_empty()) {
+        let Some((_, [p_x, p_y, v_x, v_y])) = robot_regex.captures(line).map(|c| c.extract())
+        else {
+            return Err(ParseError::unexpected(
+                input,
+                line,
+                "\"p=<x>,<y> v=<x>,<y>\"",
+            ));
+        };
+        robot_data.push(
+            (
+                parse::number::<u32>(input, p_x)?,
+                parse::number::<u32>(input, p_y)?,
+                parse::number::<i64>(input, v_x)?,
+                parse::number::<i64>(input, v_y)?,
+            )
+                .into(),
+        );
+    }
+
+    Ok(robot_data)
+}
+
+/// `<width>,<height>`, both odd so that the area has a middle row and column.
+fn parse_dimensions(value: &str) -> Result<(u32, u32), String> {
+    let dimensions = value
+        .split_once(',')
+        .and_then(|(x, y)| Some((x.parse::<u32>().ok()?, y.parse::<u32>().ok()?)));
+    match dimensions {
+        None => Err("expected <width>,<height>".to_string()),
+        Some((x, y)) if x <= 1 || y <= 1 => {
+            Err("robot area dimensions must be larger than 1".to_string())
+        }
+        Some((x, y)) if x % 2 != 1 || y % 2 != 1 => {
+            Err("robot area dimensions must be uneven".to_string())
+        }
+        Some(dimensions) => Ok(dimensions),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::testing::answer;
+
+    use super::*;
+
+    #[test]
+    fn the_largest_robot_area_does_not_overflow() {
+        let input = "robot_area_size=4294967295,4294967295\npart1_seconds=4294967295\n\
+                     tree_robots=1\np=50,50 v=9223372036854775807,-3\n";
+
+        assert_eq!(answer(Day14::solve_part1, input), "0");
+        // The robot starts in the tree's columns and rows, but the period of the area, more than
+        // a u32 holds, is computed first.
+        assert_eq!(answer(Day14::solve_part2, input), "0");
+    }
+}
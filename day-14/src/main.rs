@@ -0,0 +1,5 @@
+use aoc_common::OrExit;
+
+fn main() {
+    aoc_common::run_day::<day_14::Day14>(["safety factor: ", "seconds_until_tree: "]).or_exit();
+}
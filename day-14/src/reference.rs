@@ -0,0 +1,68 @@
+//! A slow but obviously correct solution, checked against the fast one by `aoc check`. It moves
+//! the robots one second at a time.
+
+use aoc_common::{Answer, Error, Solution};
+
+use crate::Day14;
+
+/// Moves every robot one second at a time, wrapping around the edges.
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    let (area, params) = Day14::parse(input)?;
+    let (width, height) = (i64::from(area.dimensions.0), i64::from(area.dimensions.1));
+
+    let mut quadrants = [0u64; 4];
+    for robot in area.robot_data {
+        let (mut x, mut y) = (robot.pos_x, robot.pos_y);
+        for _ in 0..params.part1_seconds {
+            x = (x + robot.vel_x).rem_euclid(width);
+            y = (y + robot.vel_y).rem_euclid(height);
+        }
+
+        let (middle_x, middle_y) = (width / 2, height / 2);
+        if x == middle_x || y == middle_y {
+            continue;
+        }
+        quadrants[usize::from(x > middle_x) + 2 * usize::from(y > middle_y)] += 1;
+    }
+    Ok(quadrants.iter().product::<u64>().into())
+}
+
+/// Moves every robot one second at a time until enough of them stand in the tree's columns and
+/// rows, or until they are all back where they started.
+pub fn part2(input: &str) -> aoc_common::Result<Answer> {
+    let (area, params) = Day14::parse(input)?;
+    let (width, height) = (i64::from(area.dimensions.0), i64::from(area.dimensions.1));
+
+    let start = area
+        .robot_data
+        .iter()
+        .map(|robot| (robot.pos_x, robot.pos_y))
+        .collect::<Vec<_>>();
+    let mut positions = start.clone();
+    let mut seconds = 0u64;
+    loop {
+        let in_tree = positions
+            .iter()
+            .filter(|&&(x, y)| {
+                params.tree_columns.contains(&(x as usize))
+                    && params.tree_rows.contains(&(y as usize))
+            })
+            .count();
+        if in_tree >= params.tree_robots {
+            return Ok(seconds.into());
+        }
+
+        for (position, robot) in positions.iter_mut().zip(&area.robot_data) {
+            position.0 = (position.0 + robot.vel_x).rem_euclid(width);
+            position.1 = (position.1 + robot.vel_y).rem_euclid(height);
+        }
+        seconds += 1;
+        if positions == start {
+            // Worded like the fast part, which gives up after the width times the height.
+            return Err(Error::NoAnswer(format!(
+                "no Christmas tree shows up within {} seconds",
+                width * height
+            )));
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    Answer, Direction, Grid, Override, ParseError, Point, Solution, debug, drawing, parse, trace,
+};
+use itertools::Itertools;
+
+mod reference;
+
+type RobotArea = Grid<char>;
+type Commands = Vec<Direction>;
+
+/// Tiles to move as (from, to, tile) triples.
+type Moves = HashSet<(Point, Point, char)>;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const INPUT_PATH: &'static str = "day-15/input.txt";
+
+    /// The warehouse, the moves and where the robot starts.
+    type Input = (RobotArea, Commands, Point);
+
+    fn parse_with(input: &str, _overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(
+        (robot_area, commands, robot_starting_location): &Self::Input,
+    ) -> aoc_common::Result<Answer> {
+        let mut cur_robot_area = robot_area.clone();
+        let mut cur_pos = *robot_starting_location;
+        for &command in commands {
+            trace!("moving {:?}{}", command, drawing!(cur_robot_area));
+            if let Some(next_pos) = move_robot_or_box(&mut cur_robot_area, (cur_pos, '@'), command)
+            {
+                cur_pos = next_pos;
+            };
+        }
+        debug!(
+            "after every move the robot is at {}{}",
+            cur_pos,
+            drawing!(cur_robot_area)
+        );
+
+        Ok(sum_gps_coordinates(cur_robot_area, 'O').into())
+    }
+
+    fn part2(
+        (robot_area, commands, robot_starting_location): &Self::Input,
+    ) -> aoc_common::Result<Answer> {
+        let mut cur_robot_area = widen(robot_area);
+        let mut cur_pos = Point::new(robot_starting_location.x * 2, robot_starting_location.y);
+        for &command in commands {
+            trace!("moving {:?}{}", command, drawing!(cur_robot_area));
+            if let Some((next_moves, latest)) =
+                move_wide_robot_or_box(&mut cur_robot_area, (cur_pos, '@'), command)
+            {
+                cur_pos = latest;
+                next_moves
+                    .iter()
+                    .sorted_by(|(_, item1, _), (_, item2, _)| match command {
+                        Direction::Up => item1.y.cmp(&item2.y),
+                        Direction::Right => item1.x.cmp(&item2.x).reverse(),
+                        Direction::Down => item1.y.cmp(&item2.y).reverse(),
+                        Direction::Left => item1.x.cmp(&item2.x),
+                    })
+                    .for_each(|&(source, target, source_char)| {
+                        cur_robot_area.set(source, '.');
+                        cur_robot_area.set(target, source_char);
+                    });
+            };
+        }
+        debug!(
+            "after every move the robot is at {}{}",
+            cur_pos,
+            drawing!(cur_robot_area)
+        );
+
+        Ok(sum_gps_coordinates(cur_robot_area, '[').into())
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part2(input))
+    }
+}
+
+/// The sum of `box_char`'s GPS coordinates, boxes being `'O'` or the left half `'['` of a wide box.
+fn sum_gps_coordinates(robot_area: RobotArea, box_char: char) -> usize {
+    robot_area
+        .iter()
+        .filter(|(_, ch)| ch.eq(&&box_char))
+        .map(|(position, _)| position.x + position.y * 100)
+        .sum()
+}
+
+/// Part 2's warehouse, where everything except the robot is twice as wide.
+fn widen(narrow_area: &RobotArea) -> RobotArea {
+    let mut robot_area = Grid::new(narrow_area.width() * 2, narrow_area.height(), '.');
+    for (Point { x, y }, ch) in narrow_area.iter() {
+        let [left, right] = match ch {
+            '#' => ['#', '#'],
+            'O' => ['[', ']'],
+            '@' => ['@', '.'],
+            _ => ['.', '.'],
+        };
+        robot_area.set((x * 2, y), left);
+        robot_area.set((x * 2 + 1, y), right);
+    }
+    robot_area
+}
+
+fn move_robot_or_box(
+    robot_area: &mut RobotArea,
+    (source, source_char): (Point, char),
+    direction: Direction,
+) -> Option<Point> {
+    let target_coords = robot_area.step(source, direction)?;
+
+    let char_at_target = robot_area.get(target_coords)?;
+
+    let valid_target_coords_opt = match char_at_target {
+        '.' => Some(target_coords),
+        'O' => {
+            let successful_move =
+                move_robot_or_box(robot_area, (target_coords, *char_at_target), direction);
+
+            trace!(
+                "target_coords: {:?}, successful_move: {:?}",
+                target_coords, successful_move
+            );
+            if successful_move.is_some() {
+                Some(target_coords)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    };
+
+    if let Some(valid_target_coords) = valid_target_coords_opt {
+        robot_area.set(source, '.');
+        robot_area.set(valid_target_coords, source_char);
+        return valid_target_coords_opt;
+    }
+    None
+}
+
+fn move_wide_robot_or_box(
+    robot_area: &mut RobotArea,
+    (source, source_char): (Point, char),
+    direction: Direction,
+) -> Option<(Moves, Point)> {
+    let target_coords = robot_area.step(source, direction)?;
+
+    let char_at_target = robot_area.get(target_coords)?;
+
+    let mut moves = HashSet::new();
+
+    let valid_target_coords_opt = match char_at_target {
+        '.' => Some(target_coords),
+        '[' => {
+            if matches!(direction, Direction::Up | Direction::Down) {
+                trace!("target_coords {:?}", target_coords);
+
+                let next_moves_1 =
+                    move_wide_robot_or_box(robot_area, (target_coords, '['), direction);
+                let right_half = robot_area.step(target_coords, Direction::Right)?;
+                let next_moves_2 = move_wide_robot_or_box(robot_area, (right_half, ']'), direction);
+
+                let total_next_moves_opt = next_moves_1.zip(next_moves_2);
+                // .map(|pair| pair.0.union(&pair.1));
+
+                if let Some((moves_1, moves_2)) = &total_next_moves_opt {
+                    moves = moves
+                        .union(&(moves_1.0.union(&moves_2.0).cloned().collect()))
+                        .cloned()
+                        .collect::<HashSet<_>>();
+                }
+                total_next_moves_opt.and(Some(target_coords))
+            } else {
+                // todo!()
+                move_wide_robot_or_box(robot_area, (target_coords, '['), direction).map(
+                    |next_moves| {
+                        moves = next_moves.0;
+                        target_coords
+                    },
+                )
+            }
+        }
+        ']' => {
+            if matches!(direction, Direction::Up | Direction::Down) {
+                trace!("target_coords {:?}", target_coords);
+
+                let next_moves_1 =
+                    move_wide_robot_or_box(robot_area, (target_coords, ']'), direction);
+                let left_half = robot_area.step(target_coords, Direction::Left)?;
+                let next_moves_2 = move_wide_robot_or_box(robot_area, (left_half, '['), direction);
+
+                let total_next_moves_opt = next_moves_1.zip(next_moves_2);
+                // .map(|pair| pair.0.union(&pair.1));
+
+                if let Some((moves_1, moves_2)) = &total_next_moves_opt {
+                    moves = moves
+                        .union(&(moves_1.0.union(&moves_2.0).cloned().collect()))
+                        .cloned()
+                        .collect::<HashSet<_>>();
+                }
+                total_next_moves_opt.and(Some(target_coords))
+            } else {
+                // todo!()
+                move_wide_robot_or_box(robot_area, (target_coords, ']'), direction).map(
+                    |next_moves| {
+                        moves = next_moves.0;
+                        target_coords
+                    },
+                )
+            }
+        }
+        _ => None,
+    };
+
+    if let Some(valid_target_coords) = valid_target_coords_opt {
+        moves.insert((source, valid_target_coords, source_char));
+        return Some((moves, valid_target_coords));
+    }
+    None
+}
+
+fn parse_input(input: &str) -> Result<(RobotArea, Commands, Point), ParseError> {
+    let (map_lines, command_lines): (Vec<&str>, Vec<&str>) = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .partition(|line| line.starts_with('#'));
+
+    for line in &map_lines {
+        parse::expect_chars(input, line, "#.O@", "'#', '.', 'O' or the robot '@'")?;
+    }
+    for line in &command_lines {
+        if !line.starts_with(['^', '>', 'v', '<']) {
+            return Err(ParseError::unexpected(
+                input,
+                line,
+                "a map row starting with '#' or a line of moves",
+            ));
+        }
+        parse::expect_chars(input, line, "^>v<", "a move '^', '>', 'v' or '<'")?;
+    }
+
+    let mut robots = map_lines.iter().flat_map(|line| line.matches('@'));
+    match (robots.next(), robots.next()) {
+        (None, _) => {
+            return Err(ParseError::unexpected_end(
+                input,
+                "the robot '@' on the map",
+            ));
+        }
+        (Some(_), Some(second)) => {
+            return Err(ParseError::invalid(
+                input,
+                second,
+                "the map can only have one robot",
+            ));
+        }
+        (Some(_), None) => {}
+    }
+
+    let robot_area = Grid::parse_rows(input, &map_lines)?;
+
+    let commands = command_lines
+        .iter()
+        .flat_map(|line| line.chars().flat_map(Direction::from_arrow))
+        .collect::<Vec<_>>();
+
+    let start_robot_location = robot_area
+        .find(|c| c.eq(&'@'))
+        .ok_or_else(|| ParseError::unexpected_end(input, "the robot '@' on the map"))?;
+
+    Ok((robot_area, commands, start_robot_location))
+}
@@ -0,0 +1,6 @@
+use aoc_common::OrExit;
+
+fn main() {
+    aoc_common::run_day::<day_15::Day15>(["sum_gps_coordinates: ", "sum_gps_coordinates: "])
+        .or_exit();
+}
@@ -0,0 +1,84 @@
+//! Slow but obviously correct solutions, checked against the fast ones by `aoc check`. Both
+//! warehouses are handled alike: a move gathers every tile pushed along, and happens only when
+//! none of them would hit a wall.
+
+use aoc_common::{Answer, Direction, Grid, Solution};
+
+use crate::Day15;
+
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    let (warehouse, moves, _) = Day15::parse(input)?;
+
+    Ok(sum_gps_coordinates(&warehouse, &moves).into())
+}
+
+pub fn part2(input: &str) -> aoc_common::Result<Answer> {
+    let (warehouse, moves, _) = Day15::parse(input)?;
+
+    let rows = warehouse
+        .rows()
+        .map(|row| {
+            row.iter()
+                .flat_map(|tile| match tile {
+                    '#' => ['#', '#'],
+                    'O' => ['[', ']'],
+                    '@' => ['@', '.'],
+                    _ => ['.', '.'],
+                })
+                .collect()
+        })
+        .collect();
+    let wide = Grid::from_rows(rows).expect("every row is widened alike");
+
+    Ok(sum_gps_coordinates(&wide, &moves).into())
+}
+
+/// Makes every move, then sums 100 times the row plus the column of every box.
+fn sum_gps_coordinates(warehouse: &Grid<char>, moves: &[Direction]) -> usize {
+    let mut warehouse = warehouse.clone();
+    for &direction in moves {
+        warehouse = make_move(&warehouse, direction);
+    }
+
+    warehouse
+        .iter()
+        .filter(|(_, tile)| **tile == 'O' || **tile == '[')
+        .map(|(position, _)| 100 * position.y + position.x)
+        .sum()
+}
+
+fn make_move(warehouse: &Grid<char>, direction: Direction) -> Grid<char> {
+    let (dx, dy) = direction.offset();
+    let (dx, dy) = (dx as i64, dy as i64);
+    let robot = warehouse
+        .find(|&tile| tile == '@')
+        .expect("the robot stays on the map");
+
+    let mut pushed = vec![];
+    let mut pending = vec![(robot.x as i64, robot.y as i64)];
+    while let Some((x, y)) = pending.pop() {
+        if pushed.contains(&(x, y)) {
+            continue;
+        }
+        pushed.push((x, y));
+
+        let next = (x + dx, y + dy);
+        match warehouse.get_signed(next) {
+            Some('O') => pending.push(next),
+            Some('[') => pending.extend([next, (next.0 + 1, next.1)]),
+            Some(']') => pending.extend([next, (next.0 - 1, next.1)]),
+            Some('.') => {}
+            _ => return warehouse.clone(),
+        }
+    }
+
+    let mut moved = warehouse.clone();
+    for &(x, y) in &pushed {
+        moved.set((x as usize, y as usize), '.');
+    }
+    for &(x, y) in &pushed {
+        let tile = warehouse[(x as usize, y as usize)];
+        moved.set(((x + dx) as usize, (y + dy) as usize), tile);
+    }
+    moved
+}
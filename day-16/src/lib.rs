@@ -0,0 +1,236 @@
+#[cfg(feature = "visualize")]
+use aoc_common::log::{self, Level};
+use aoc_common::search::{self, Search};
+use aoc_common::{Answer, Direction, Error, Grid, Override, ParseError, Point, Solution, parse};
+#[cfg(feature = "visualize")]
+use aoc_common::{debug, trace};
+
+mod reference;
+
+type Maze = Grid<char>;
+
+/// A tile and the direction the reindeer faces on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Reindeer {
+    position: Point,
+    facing: Direction,
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const INPUT_PATH: &'static str = "day-16/input.txt";
+
+    /// The maze, the start and the end.
+    type Input = (Maze, Point, Point);
+
+    fn parse_with(input: &str, _overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1((maze, start, end): &Self::Input) -> aoc_common::Result<Answer> {
+        let paths = best_paths(maze, *start, *end);
+        let best_rating = paths.goal_cost().ok_or_else(|| {
+            Error::NoAnswer("no path leads from the start to the end".to_string())
+        })?;
+
+        #[cfg(feature = "visualize")]
+        if let Some(path) = paths.goal().and_then(|goal| paths.path_to(goal)) {
+            debug!("one of the best paths:\n{}", draw_path(maze, &path));
+        }
+
+        Ok(best_rating.into())
+    }
+
+    fn part2((maze, start, end): &Self::Input) -> aoc_common::Result<Answer> {
+        // The two searches are independent, only `parallel` runs them side by side.
+        #[cfg(feature = "parallel")]
+        let (paths, paths_back) = rayon::join(
+            || best_paths(maze, *start, *end),
+            || best_paths_back(maze, *start, *end),
+        );
+        #[cfg(not(feature = "parallel"))]
+        let (paths, paths_back) = (
+            best_paths(maze, *start, *end),
+            best_paths_back(maze, *start, *end),
+        );
+        if paths.goal().is_none() {
+            return Err(Error::NoAnswer(
+                "no path leads from the start to the end".to_string(),
+            ));
+        }
+
+        let seats = seats_between(maze, &paths, &paths_back);
+
+        // There can be exponentially many best paths, only walk them when they are traced.
+        #[cfg(feature = "visualize")]
+        if log::enabled(Level::Trace, module_path!()) {
+            for goal in paths.goals() {
+                for path in paths.paths_to(goal) {
+                    trace!("best path:\n{}", draw_path(maze, &path));
+                }
+            }
+        }
+
+        Ok(seats.into())
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part2(input))
+    }
+}
+
+/// Every lowest scoring path from the start, facing east, to the end.
+fn best_paths(maze: &Maze, start: Point, end: Point) -> Search<Reindeer> {
+    let start = Reindeer {
+        position: start,
+        facing: Direction::Right,
+    };
+    search::dijkstra(
+        [start],
+        |&reindeer| moves(maze, reindeer),
+        |reindeer| reindeer.position.eq(&end),
+    )
+}
+
+/// Like [`best_paths`], from the end facing any direction back to the start facing east.
+fn best_paths_back(maze: &Maze, start: Point, end: Point) -> Search<Reindeer> {
+    let start = Reindeer {
+        position: start,
+        facing: Direction::Right,
+    };
+    search::dijkstra(
+        Direction::ALL.map(|facing| Reindeer {
+            position: end,
+            facing,
+        }),
+        |&reindeer| moves_back(maze, reindeer),
+        |reindeer| reindeer.eq(&start),
+    )
+}
+
+/// The tiles on any best path, where the best rating to a reindeer and the best rating from it
+/// to the end add up to the best rating of all.
+fn seats_between(maze: &Maze, paths: &Search<Reindeer>, paths_back: &Search<Reindeer>) -> usize {
+    let Some(best_rating) = paths.goal_cost() else {
+        return 0;
+    };
+
+    maze.positions()
+        .filter(|&position| {
+            Direction::ALL.into_iter().any(|facing| {
+                let reindeer = Reindeer { position, facing };
+                match (paths.cost(&reindeer), paths_back.cost(&reindeer)) {
+                    (Some(to), Some(from)) => to + from == best_rating,
+                    _ => false,
+                }
+            })
+        })
+        .count()
+}
+
+/// Stepping forward costs 1 point, turning a quarter either way 1000.
+fn moves(maze: &Maze, reindeer: Reindeer) -> Vec<(Reindeer, u64)> {
+    let forward = maze
+        .step(reindeer.position, reindeer.facing)
+        .filter(|&next| maze[next].ne(&'#'))
+        .map(|next| (reindeer.moved_to(next), 1));
+
+    reindeer.turns().into_iter().chain(forward).collect()
+}
+
+/// The moves that end in `reindeer`, [`moves`] turned around.
+fn moves_back(maze: &Maze, reindeer: Reindeer) -> Vec<(Reindeer, u64)> {
+    let backward = maze
+        .step(reindeer.position, reindeer.facing.opposite())
+        .filter(|&previous| maze[previous].ne(&'#'))
+        .map(|previous| (reindeer.moved_to(previous), 1));
+
+    reindeer.turns().into_iter().chain(backward).collect()
+}
+
+impl Reindeer {
+    fn moved_to(self, position: Point) -> Reindeer {
+        Reindeer { position, ..self }
+    }
+
+    /// Turning a quarter either way, at 1000 points each.
+    fn turns(self) -> [(Reindeer, u64); 2] {
+        [
+            (self.facing.turn_left(), 1000),
+            (self.facing.turn_right(), 1000),
+        ]
+        .map(|(facing, cost)| (Reindeer { facing, ..self }, cost))
+    }
+}
+
+/// The maze with the reindeer's heading drawn on every tile of `path`.
+#[cfg(feature = "visualize")]
+fn draw_path(maze: &Maze, path: &[Reindeer]) -> Maze {
+    let mut area_to_print = maze.clone();
+
+    for reindeer in path {
+        area_to_print.set(reindeer.position, reindeer.facing.arrow());
+    }
+
+    area_to_print
+}
+
+fn parse_input(input: &str) -> Result<(Maze, Point, Point), ParseError> {
+    let maze_lines = input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+
+    for line in &maze_lines {
+        if !line.starts_with('#') {
+            return Err(ParseError::unexpected(
+                input,
+                line,
+                "a maze row starting with '#'",
+            ));
+        }
+        parse::expect_chars(
+            input,
+            line,
+            "#.SE",
+            "'#', '.', the start 'S' or the end 'E'",
+        )?;
+    }
+    expect_one(input, &maze_lines, "S", "start")?;
+    expect_one(input, &maze_lines, "E", "end")?;
+
+    let maze = Grid::parse_rows(input, &maze_lines)?;
+
+    let start_location = maze
+        .find(|c| c.eq(&'S'))
+        .ok_or_else(|| ParseError::unexpected_end(input, "the start 'S'"))?;
+
+    let end_location = maze
+        .find(|c| c.eq(&'E'))
+        .ok_or_else(|| ParseError::unexpected_end(input, "the end 'E'"))?;
+
+    Ok((maze, start_location, end_location))
+}
+
+fn expect_one(input: &str, maze_lines: &[&str], tile: &str, name: &str) -> Result<(), ParseError> {
+    let mut found = maze_lines.iter().flat_map(|line| line.matches(tile));
+    match (found.next(), found.next()) {
+        (None, _) => Err(ParseError::unexpected_end(
+            input,
+            format!("the {} '{}'", name, tile),
+        )),
+        (Some(_), Some(second)) => Err(ParseError::invalid(
+            input,
+            second,
+            format!("the maze can only have one {} '{}'", name, tile),
+        )),
+        (Some(_), None) => Ok(()),
+    }
+}
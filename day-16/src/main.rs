@@ -0,0 +1,5 @@
+use aoc_common::OrExit;
+
+fn main() {
+    aoc_common::run_day::<day_16::Day16>(["Rating: ", "seats "]).or_exit();
+}
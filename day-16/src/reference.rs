@@ -0,0 +1,94 @@
+//! Slow but obviously correct solutions, checked against the fast ones by `aoc check`. They
+//! lower the score of every position and heading until no move lowers any of them further,
+//! instead of searching in order of score.
+
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{Answer, Direction, Error, Grid, Point, Solution};
+
+use crate::Day16;
+
+/// A tile and the direction the reindeer faces on it.
+type State = (Point, Direction);
+
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    let (maze, start, end) = Day16::parse(input)?;
+    let scores = lowest_scores(&maze, start);
+
+    Ok(best_score(&scores, end)?.into())
+}
+
+/// Walks back from the end over every move that keeps to the lowest scores.
+pub fn part2(input: &str) -> aoc_common::Result<Answer> {
+    let (maze, start, end) = Day16::parse(input)?;
+    let scores = lowest_scores(&maze, start);
+    let best = best_score(&scores, end)?;
+
+    let mut on_best_paths = Direction::ALL
+        .into_iter()
+        .map(|direction| (end, direction))
+        .filter(|state| scores.get(state) == Some(&best))
+        .collect::<HashSet<_>>();
+    loop {
+        let before = on_best_paths.len();
+        for (&state, &score) in &scores {
+            let leads_on = moves(&maze, state).into_iter().any(|(next, cost)| {
+                on_best_paths.contains(&next) && scores.get(&next) == Some(&(score + cost))
+            });
+            if leads_on {
+                on_best_paths.insert(state);
+            }
+        }
+        if on_best_paths.len() == before {
+            break;
+        }
+    }
+
+    let seats = on_best_paths
+        .iter()
+        .map(|&(tile, _)| tile)
+        .collect::<HashSet<_>>();
+    Ok(seats.len().into())
+}
+
+/// The lowest score of every state the reindeer can reach, starting east.
+fn lowest_scores(maze: &Grid<char>, start: Point) -> HashMap<State, u64> {
+    let mut scores = HashMap::from([((start, Direction::Right), 0)]);
+    loop {
+        let mut lowered = false;
+        for (state, score) in scores.clone() {
+            for (next, cost) in moves(maze, state) {
+                let lowest = scores.entry(next).or_insert(u64::MAX);
+                if score + cost < *lowest {
+                    *lowest = score + cost;
+                    lowered = true;
+                }
+            }
+        }
+        if !lowered {
+            return scores;
+        }
+    }
+}
+
+fn best_score(scores: &HashMap<State, u64>, end: Point) -> aoc_common::Result<u64> {
+    Direction::ALL
+        .into_iter()
+        .filter_map(|direction| scores.get(&(end, direction)).copied())
+        .min()
+        .ok_or_else(|| Error::NoAnswer("no path leads from the start to the end".to_string()))
+}
+
+/// Stepping forward costs 1 point, turning a quarter either way 1000.
+fn moves(maze: &Grid<char>, (tile, direction): State) -> Vec<(State, u64)> {
+    let mut moves = vec![
+        ((tile, direction.turn_left()), 1000),
+        ((tile, direction.turn_right()), 1000),
+    ];
+    if let Some(next) = maze.step(tile, direction) {
+        if maze[next] != '#' {
+            moves.push(((next, direction), 1));
+        }
+    }
+    moves
+}
@@ -0,0 +1,345 @@
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use aoc_common::{
+    Answer, Error, Header, Override, Param, ParseError, Solution, debug, parse, trace,
+};
+use itertools::Itertools;
+use regex::Regex;
+
+mod reference;
+
+/// Registers A, B and C and the program.
+type Computer = (i64, i64, i64, Vec<i64>);
+
+pub struct Day17;
+
+/// The puzzle parameters, see [`Day17::PARAMS`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    /// How many bits of register A the program shifts out on every pass, 3 in the puzzle's
+    /// programs. Part 2 puts them back one pass at a time.
+    pub a_shift: u32,
+}
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const INPUT_PATH: &'static str = "day-17/input.txt";
+    const PARAMS: &'static [Param] = &[Param {
+        name: "a_shift",
+        doc: "how many bits of register A the program shifts out on every pass, from 1 to 16",
+        default: "3",
+    }];
+    const NO_REFERENCE: &'static str =
+        "a program prints itself only for values of register A far too large to try one by one";
+
+    type Input = (Computer, Params);
+
+    fn parse_with(input: &str, overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        let (header, computer) = Header::split(input, Self::PARAMS, overrides)?;
+        let params = Params {
+            a_shift: header.get_with("a_shift", |value| match value.parse::<u32>() {
+                Ok(shift @ 1..=16) => Ok(shift),
+                _ => Err("expected a shift of 1 to 16 bits".to_string()),
+            })?,
+        };
+        Ok((parse_regex(input, computer)?, params))
+    }
+
+    fn part1(((reg_a, reg_b, reg_c, prog), _): &Self::Input) -> aoc_common::Result<Answer> {
+        let (reg_a, reg_b, reg_c) = (*reg_a, *reg_b, *reg_c);
+        let output_formatted = run_program((reg_a, reg_b, reg_c), prog, false)
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>()
+            .join(",");
+
+        Ok(output_formatted.into())
+    }
+
+    fn part2(((_reg_a, _reg_b, _reg_c, prog), params): &Self::Input) -> aoc_common::Result<Answer> {
+        let desired_output = prog.iter().rev().copied().collect_vec();
+        let possible_reg_a_vals = backtrack(vec![0], prog, &desired_output, params.a_shift);
+
+        debug!("possible_reg_a_vals {:?}", possible_reg_a_vals);
+
+        let res = possible_reg_a_vals.iter().min().ok_or_else(|| {
+            Error::NoAnswer("no value of register A makes the program output itself".to_string())
+        })?;
+
+        Ok((*res).into())
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+}
+
+fn backtrack(
+    reg_a_after: Vec<i64>,
+    prog: &[i64],
+    desired_output: &[i64],
+    a_shift: u32,
+) -> Vec<i64> {
+    let target_output = match desired_output.first() {
+        Some(target) => *target,
+        None => return reg_a_after,
+    };
+
+    let reg_a_vals_before = reg_a_after
+        .iter()
+        .flat_map(|after_val| get_possible_reg_a_vals_before(*after_val, a_shift))
+        .filter(|possible_before_val| {
+            run_one_iteration(*possible_before_val, prog)
+                .unwrap_or(-1)
+                .eq(&target_output)
+        })
+        .collect_vec();
+
+    backtrack(reg_a_vals_before, prog, &desired_output[1..], a_shift)
+}
+
+/// The values register A can have had before a pass that shifted out `a_shift` bits.
+fn get_possible_reg_a_vals_before(reg_a_after: i64, a_shift: u32) -> Vec<i64> {
+    let denom = 2_i64.pow(a_shift);
+    let Some(low_val) = reg_a_after.checked_mul(denom) else {
+        return vec![];
+    };
+
+    let mut ret = vec![];
+    for value in low_val.max(1)..(low_val + denom) {
+        ret.push(value);
+    }
+    ret
+}
+
+fn run_one_iteration(reg_a: i64, prog: &[i64]) -> Option<i64> {
+    // my input has only one out opcode
+    run_program((reg_a, 0, 0), prog, true).first().copied()
+}
+
+/// Runs `program` until it halts, or with `single_iteration` until its first output without
+/// following the jump back to the start.
+fn run_program(
+    (reg_a, reg_b, reg_c): (i64, i64, i64),
+    program: &[i64],
+    single_iteration: bool,
+) -> Vec<i64> {
+    let register_a = Rc::new(Cell::new(reg_a));
+    let register_b = Rc::new(Cell::new(reg_b));
+    let register_c: Rc<Cell<i64>> = Rc::new(Cell::new(reg_c));
+    let instruction_pointer: Rc<Cell<i64>> = Rc::new(Cell::new(0i64));
+    let output: Rc<RefCell<Vec<i64>>> = Rc::new(RefCell::new(vec![]));
+
+    let adv = make_div_op(
+        instruction_pointer.clone(),
+        register_a.clone(),
+        register_a.clone(),
+    );
+    let bdv = make_div_op(
+        instruction_pointer.clone(),
+        register_a.clone(),
+        register_b.clone(),
+    );
+    let cdv = make_div_op(
+        instruction_pointer.clone(),
+        register_a.clone(),
+        register_c.clone(),
+    );
+
+    let bxl = make_xor_op(instruction_pointer.clone(), register_b.clone());
+    let bxc = make_xor_op(instruction_pointer.clone(), register_b.clone());
+    let out = make_out_op(instruction_pointer.clone(), output.clone());
+    let bst = make_bst_op(instruction_pointer.clone(), register_b.clone());
+    let jnz = make_jump_op(
+        instruction_pointer.clone(),
+        register_a.clone(),
+        !single_iteration,
+    );
+
+    let get_combo_operand =
+        make_combo_operand_getter(register_a.clone(), register_b.clone(), register_c.clone());
+
+    while let Some((opcode, operand)) =
+        get_next_opcode_and_operand(instruction_pointer.clone(), program)
+    {
+        match opcode {
+            0 => adv(get_combo_operand(operand)),
+            1 => bxl(operand),
+            2 => bst(get_combo_operand(operand)),
+            3 => jnz(operand),
+            4 => bxc(register_c.get()),
+            5 => out(get_combo_operand(operand)),
+            6 => bdv(get_combo_operand(operand)),
+            7 => cdv(get_combo_operand(operand)),
+            _ => panic!("illegal opcode"),
+        }
+
+        trace!(
+            "opcode {} operand {}: ip {}, A {}, B {}, C {}, output {:?}",
+            opcode,
+            operand,
+            instruction_pointer.get(),
+            register_a.get(),
+            register_b.get(),
+            register_c.get(),
+            output.borrow()
+        );
+
+        if single_iteration && !output.borrow().is_empty() {
+            break;
+        }
+    }
+
+    output.take()
+}
+
+fn get_next_opcode_and_operand(
+    instruction_pointer: Rc<Cell<i64>>,
+    program: &[i64],
+) -> Option<(i64, i64)> {
+    let index: usize = instruction_pointer.get().try_into().ok()?;
+    let opcode = program.get(index)?;
+    let operand = program.get(index + 1)?;
+    Some((*opcode, *operand))
+}
+
+fn make_combo_operand_getter(
+    register_a: Rc<Cell<i64>>,
+    register_b: Rc<Cell<i64>>,
+    register_c: Rc<Cell<i64>>,
+) -> impl Fn(i64) -> i64 {
+    move |operand| match operand {
+        0..=3 => operand,
+        4 => register_a.get(),
+        5 => register_b.get(),
+        6 => register_c.get(),
+        _ => panic!("illegal combo operand"),
+    }
+}
+
+fn make_div_op(
+    instruction_pointer: Rc<Cell<i64>>,
+    numerator_reg: Rc<Cell<i64>>,
+    target_reg: Rc<Cell<i64>>,
+) -> impl Fn(i64) {
+    move |operand| {
+        let denom: Result<u32, _> = operand.try_into();
+        let new_val = match denom {
+            Ok(rhs) => numerator_reg.get().checked_shr(rhs).unwrap_or(0),
+            Err(_) => panic!("Cannot convert {} into u32", operand),
+        };
+        target_reg.set(new_val);
+        instruction_pointer.set(instruction_pointer.get() + 2);
+    }
+}
+
+fn make_xor_op(instruction_pointer: Rc<Cell<i64>>, target_reg: Rc<Cell<i64>>) -> impl Fn(i64) {
+    move |operand| {
+        let new_val = target_reg.get() ^ operand;
+        target_reg.set(new_val);
+        instruction_pointer.set(instruction_pointer.get() + 2);
+    }
+}
+
+fn make_out_op(instruction_pointer: Rc<Cell<i64>>, output: Rc<RefCell<Vec<i64>>>) -> impl Fn(i64) {
+    move |operand| {
+        let out_val = operand % 8;
+        output.borrow_mut().push(out_val);
+        instruction_pointer.set(instruction_pointer.get() + 2);
+    }
+}
+
+fn make_bst_op(instruction_pointer: Rc<Cell<i64>>, target_reg: Rc<Cell<i64>>) -> impl Fn(i64) {
+    move |operand| {
+        let out_val = operand % 8;
+        target_reg.set(out_val);
+        instruction_pointer.set(instruction_pointer.get() + 2);
+    }
+}
+
+fn make_jump_op(
+    instruction_pointer: Rc<Cell<i64>>,
+    toggle_reg: Rc<Cell<i64>>,
+    jumps: bool,
+) -> impl Fn(i64) {
+    move |operand| {
+        if jumps && toggle_reg.get() != 0 {
+            instruction_pointer.set(operand);
+        } else {
+            instruction_pointer.set(instruction_pointer.get() + 2);
+        }
+    }
+}
+
+/// `computer` is the part of `input` after the header.
+fn parse_regex(input: &str, computer: &str) -> Result<Computer, ParseError> {
+    let program_re = Regex::new(r"^Program: (?<program>\d+(?:,\d+)*)$").unwrap();
+
+    let mut lines = computer.lines().filter(|line| !line.trim().is_empty());
+    let mut registers = [0i64; 3];
+
+    for (register, name) in registers.iter_mut().zip(['A', 'B', 'C']) {
+        let re = Regex::new(&format!(r"^Register {}: (?<value>\d+)$", name)).unwrap();
+        let expected = format!("\"Register {}: <number>\"", name);
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::unexpected_end(input, expected.as_str()))?;
+        let captures = re
+            .captures(line)
+            .ok_or_else(|| ParseError::unexpected(input, line, expected.as_str()))?;
+        *register = parse::number::<i64>(input, captures.name("value").unwrap().as_str())?;
+    }
+    let [register_a, register_b, register_c] = registers;
+
+    let expected = "\"Program: <opcode>,<operand>,...\"";
+    let line = lines
+        .next()
+        .ok_or_else(|| ParseError::unexpected_end(input, expected))?;
+    let captures = program_re
+        .captures(line)
+        .ok_or_else(|| ParseError::unexpected(input, line, expected))?;
+    let tokens = captures
+        .name("program")
+        .unwrap()
+        .as_str()
+        .split(',')
+        .collect::<Vec<_>>();
+    let program = tokens
+        .iter()
+        .map(|token| match parse::number::<i64>(input, token)? {
+            value @ 0..=7 => Ok(value),
+            _ => Err(ParseError::unexpected(
+                input,
+                token,
+                "a 3-bit number from 0 to 7",
+            )),
+        })
+        .collect::<Result<Vec<i64>, _>>()?;
+
+    // The instructions 0, 2, 5, 6 and 7 take a combo operand, which can not be 7.
+    for (index, pair) in program.chunks(2).enumerate() {
+        if let [0 | 2 | 5 | 6 | 7, 7] = pair {
+            return Err(ParseError::invalid(
+                input,
+                tokens[index * 2 + 1],
+                "combo operand 7 is reserved and does not appear in valid programs",
+            ));
+        }
+    }
+
+    if let Some(extra) = lines.next() {
+        return Err(ParseError::unexpected(
+            input,
+            extra,
+            "nothing after the program",
+        ));
+    }
+
+    debug!(
+        "registers A {}, B {}, C {}, program {:?}",
+        register_a, register_b, register_c, program
+    );
+
+    Ok((register_a, register_b, register_c, program))
+}
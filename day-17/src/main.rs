@@ -0,0 +1,5 @@
+use aoc_common::OrExit;
+
+fn main() {
+    aoc_common::run_day::<day_17::Day17>(["output: ", "Register A value: "]).or_exit();
+}
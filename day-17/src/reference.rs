@@ -0,0 +1,43 @@
+//! A slow but obviously correct solution, checked against the fast one by `aoc check`. It runs
+//! the program with one plain loop over the instructions. There is none for part 2, see
+//! [`Day17::NO_REFERENCE`].
+
+use aoc_common::{Answer, Solution};
+
+use crate::Day17;
+
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    let ((a, b, c, program), _) = Day17::parse(input)?;
+    let mut registers = [a, b, c].map(i128::from);
+
+    let mut output = vec![];
+    let mut pointer = 0;
+    while pointer + 1 < program.len() {
+        let (opcode, operand) = (program[pointer], i128::from(program[pointer + 1]));
+        let combo = match operand {
+            0..=3 => operand,
+            4..=6 => registers[operand as usize - 4],
+            // Reserved, so only ever a literal operand.
+            _ => 0,
+        };
+        // A divided by 2 to the power of the combo operand, rounded down.
+        let divided = match u32::try_from(combo) {
+            Ok(power) if power < 127 => registers[0] / 2i128.pow(power),
+            _ => 0,
+        };
+
+        pointer += 2;
+        match opcode {
+            0 => registers[0] = divided,
+            1 => registers[1] ^= operand,
+            2 => registers[1] = combo % 8,
+            3 if registers[0] != 0 => pointer = operand as usize,
+            3 => {}
+            4 => registers[1] ^= registers[2],
+            5 => output.push((combo % 8).to_string()),
+            6 => registers[1] = divided,
+            _ => registers[2] = divided,
+        }
+    }
+    Ok(output.join(",").into())
+}
@@ -0,0 +1,171 @@
+use aoc_common::{
+    Answer, Error, Grid, Header, Override, Param, ParseError, Point, Solution, debug, parse, search,
+};
+#[cfg(feature = "parallel")]
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+mod reference;
+
+pub struct Day18;
+
+/// The puzzle parameters, see [`Day18::PARAMS`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    /// The width and height of the memory space.
+    pub memory_size: usize,
+    /// How many bytes have fallen in part 1.
+    pub fallen_bytes: usize,
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const INPUT_PATH: &'static str = "day-18/input.txt";
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "memory_size",
+            doc: "the width and height of the memory space, 7 in the example",
+            default: "71",
+        },
+        Param {
+            name: "fallen_bytes",
+            doc: "how many bytes have fallen in part 1, 12 in the example",
+            default: "1024",
+        },
+    ];
+
+    type Input = (Vec<Point>, Params);
+
+    fn parse_with(input: &str, overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        let (header, bytes) = Header::split(input, Self::PARAMS, overrides)?;
+        let params = Params {
+            memory_size: header.get_with("memory_size", |value| match value.parse::<usize>() {
+                Ok(size) if size > 0 => Ok(size),
+                _ => Err("expected a size larger than 0".to_string()),
+            })?,
+            fallen_bytes: header.number("fallen_bytes")?,
+        };
+        Ok((parse_input(input, bytes, params.memory_size)?, params))
+    }
+
+    fn part1((input, params): &Self::Input) -> aoc_common::Result<Answer> {
+        let size = params.memory_size;
+        debug!("memory space is {0}x{0}", size);
+
+        let map = make_map(&input[..params.fallen_bytes.min(input.len())], size);
+
+        let res = shortest_path(&map)
+            .ok_or_else(|| Error::NoAnswer("no path leads to the exit".to_string()))?;
+
+        Ok(res.into())
+    }
+
+    fn part2((input, params): &Self::Input) -> aoc_common::Result<Answer> {
+        let size = params.memory_size;
+
+        // The last byte to fall is the one that cuts off the longest prefix that still has a path.
+        // When all of them leave a path, that prefix is the whole list and no byte follows it.
+        let has_path =
+            |&limiter: &usize| shortest_path(&make_map(&input[0..limiter], size)).is_some();
+        #[cfg(feature = "parallel")]
+        let longest_prefix = (0..input.len() + 1)
+            .into_par_iter()
+            .rev()
+            .find_first(has_path);
+        #[cfg(not(feature = "parallel"))]
+        let longest_prefix = (0..input.len() + 1).rev().find(has_path);
+        let last_addition = longest_prefix.and_then(|limiter| input.get(limiter));
+
+        let byte = last_addition
+            .ok_or_else(|| Error::NoAnswer("no byte cuts off the path to the exit".to_string()))?;
+
+        Ok(byte.to_string().into())
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part2(input))
+    }
+}
+
+/// The number of steps from the top left corner to the bottom right one.
+fn shortest_path(map: &Grid<char>) -> Option<u64> {
+    let goal = Point::new(map.width() - 1, map.height() - 1);
+    search::bfs(
+        [Point::new(0, 0)],
+        |&coords| {
+            map.neighbours_4(coords)
+                .filter(|&next_coord| map[next_coord].eq(&'.'))
+        },
+        |&coords| coords.eq(&goal),
+    )
+    .goal_cost()
+}
+
+/// Parsing checks that every byte falls inside the `size` by `size` memory space.
+fn make_map(input: &[Point], size: usize) -> Grid<char> {
+    let mut res = Grid::new(size, size, '.');
+    for &obstacle in input {
+        res[obstacle] = '#';
+    }
+    res
+}
+
+/// `bytes` is the part of `input` after the header.
+fn parse_input(input: &str, bytes: &str, size: usize) -> Result<Vec<Point>, ParseError> {
+    let mut res = vec![];
+    for line in bytes.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let (x, y) = line
+            .split_once(',')
+            .ok_or_else(|| ParseError::unexpected_end_of(input, line, "',' and a y coordinate"))?;
+        res.push(Point::new(
+            coordinate(input, x, size)?,
+            coordinate(input, y, size)?,
+        ));
+    }
+    Ok(res)
+}
+
+fn coordinate(input: &str, token: &str, size: usize) -> Result<usize, ParseError> {
+    match parse::number::<usize>(input, token)? {
+        value if value < size => Ok(value),
+        _ => Err(ParseError::unexpected(
+            input,
+            token,
+            format!("a coordinate from 0 to {}", size - 1),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::testing::answer;
+
+    use super::*;
+
+    #[test]
+    fn the_memory_space_is_read_from_the_header() {
+        // Without its header, the 7x7 example falls into the default 71x71 memory space.
+        let example = aoc_common::read_to_string("test-inputs/18.txt").unwrap();
+        let (_, bytes) = example.split_once("fallen_bytes=12\n").unwrap();
+        assert_eq!(answer(Day18::solve_part1, &example), "22");
+        assert_eq!(answer(Day18::solve_part1, bytes), "146");
+        assert!(matches!(
+            Day18::parse(&format!("memory_size=5\n{}", bytes)),
+            Err(Error::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn no_byte_cuts_off_the_path_when_all_of_them_leave_one() {
+        // The input `aoc check 18 --seed 42` found the fast part answering "6,8" for.
+        let input = "6,8\n";
+        assert!(matches!(Day18::solve_part2(input), Err(Error::NoAnswer(_))));
+        assert!(matches!(
+            Day18::reference_part2(input),
+            Some(Err(Error::NoAnswer(_)))
+        ));
+    }
+}
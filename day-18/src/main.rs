@@ -0,0 +1,5 @@
+use aoc_common::OrExit;
+
+fn main() {
+    aoc_common::run_day::<day_18::Day18>(["Solution length: ", "Last addition: "]).or_exit();
+}
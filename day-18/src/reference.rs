@@ -0,0 +1,62 @@
+//! Slow but obviously correct solutions, checked against the fast ones by `aoc check`. They
+//! find paths by flooding the memory space one step at a time.
+
+use std::collections::HashSet;
+
+use aoc_common::{Answer, Error, Point, Solution};
+
+use crate::Day18;
+
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    let (bytes, params) = Day18::parse(input)?;
+    let fallen = params.fallen_bytes.min(bytes.len());
+
+    let steps = steps_to_exit(&bytes[..fallen], params.memory_size)
+        .ok_or_else(|| Error::NoAnswer("no path leads to the exit".to_string()))?;
+    Ok(steps.into())
+}
+
+/// Lets the bytes fall one at a time until one cuts off the exit.
+pub fn part2(input: &str) -> aoc_common::Result<Answer> {
+    let (bytes, params) = Day18::parse(input)?;
+
+    let byte = (1..=bytes.len())
+        .find(|&fallen| steps_to_exit(&bytes[..fallen], params.memory_size).is_none())
+        .map(|fallen| bytes[fallen - 1])
+        .ok_or_else(|| Error::NoAnswer("no byte cuts off the path to the exit".to_string()))?;
+    Ok(byte.to_string().into())
+}
+
+/// The number of steps from the top left corner to the bottom right one around `corrupted`.
+fn steps_to_exit(corrupted: &[Point], size: usize) -> Option<u64> {
+    let corrupted = corrupted.iter().collect::<HashSet<_>>();
+    let open =
+        |(x, y): (usize, usize)| x < size && y < size && !corrupted.contains(&Point::new(x, y));
+
+    let mut reached = HashSet::from([(0, 0)]);
+    let mut frontier = vec![(0usize, 0usize)];
+    let mut steps = 0;
+    while !frontier.is_empty() {
+        if reached.contains(&(size - 1, size - 1)) {
+            return Some(steps);
+        }
+
+        let mut next = vec![];
+        for (x, y) in frontier {
+            let neighbours = [
+                (x + 1, y),
+                (x, y + 1),
+                (x.wrapping_sub(1), y),
+                (x, y.wrapping_sub(1)),
+            ];
+            for neighbour in neighbours {
+                if open(neighbour) && reached.insert(neighbour) {
+                    next.push(neighbour);
+                }
+            }
+        }
+        frontier = next;
+        steps += 1;
+    }
+    None
+}
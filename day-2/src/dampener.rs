@@ -0,0 +1,230 @@
+//! The Problem Dampener for any number of removed levels, in time linear in the length of the
+//! report for a fixed number of removals.
+//!
+//! A report is safe after removals when the levels it keeps are safe, and whether the next kept
+//! level may follow only depends on the last kept one. So for every level it is enough to know
+//! the fewest removals that make the report safe up to it with it kept, which follows from the
+//! at most `k + 1` levels before it that can be the previous kept one.
+
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+/// What makes a report safe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// The smallest change between two levels that are not the same.
+    pub min_step: u32,
+    /// The largest change between two levels.
+    pub max_step: u32,
+    /// Whether a report has to keep changing, or may also stay at a level. Either way it may not
+    /// turn around.
+    pub strict: bool,
+}
+
+impl Rules {
+    /// Levels that always go up or always go down, by 1 to 3.
+    pub const PUZZLE: Rules = Rules {
+        min_step: 1,
+        max_step: 3,
+        strict: true,
+    };
+
+    /// Whether `step` may follow in a report going up, or going down when `ascending` is false.
+    pub fn allows(&self, step: i64, ascending: bool) -> bool {
+        if step == 0 {
+            return !self.strict;
+        }
+        (step > 0) == ascending
+            && (u64::from(self.min_step)..=u64::from(self.max_step)).contains(&step.unsigned_abs())
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Rules {
+        Rules::PUZZLE
+    }
+}
+
+/// Whether `report` is safe once at most `removals` of its levels are removed.
+pub fn is_safe_after_removing(report: &[i32], removals: usize, rules: &Rules) -> bool {
+    fewest_removals(report, removals, rules).is_some()
+}
+
+/// The fewest levels to remove so that `report` is safe, when that is at most `removals`.
+/// Removing every level leaves a safe, empty report.
+pub fn fewest_removals(report: &[i32], removals: usize, rules: &Rules) -> Option<usize> {
+    levels_to_remove(report, removals, rules).map(|removed| removed.len())
+}
+
+/// The indices of the fewest levels to remove so that `report` is safe, in order, when that is
+/// at most `removals`. Of several ways to remove as few, one.
+pub fn levels_to_remove(report: &[i32], removals: usize, rules: &Rules) -> Option<Vec<usize>> {
+    [true, false]
+        .into_iter()
+        .filter_map(|ascending| {
+            let going = Going::new(report, removals, rules, ascending);
+            let (_, mut last_kept) = going.ends().min_by_key(|&(total, _)| total)?;
+
+            let mut kept = vec![false; report.len()];
+            while let Some(index) = last_kept {
+                kept[index] = true;
+                last_kept = going
+                    .previous_kept(index)
+                    .next()
+                    .expect("a kept level follows another one or only removed ones");
+            }
+            Some((0..report.len()).filter(|&index| !kept[index]).collect())
+        })
+        .min_by_key(Vec::len)
+}
+
+/// Every way of removing the fewest levels so that `report` is safe, when that is at most
+/// `removals`, each with the indices in order and the ways in order, empty otherwise.
+pub fn every_levels_to_remove(report: &[i32], removals: usize, rules: &Rules) -> Vec<Vec<usize>> {
+    let going = [true, false].map(|ascending| Going::new(report, removals, rules, ascending));
+    let Some(fewest) = going
+        .iter()
+        .flat_map(|going| going.ends().map(|(total, _)| total))
+        .min()
+    else {
+        return vec![];
+    };
+
+    // A set, as a report that keeps at most one level, or only equal ones, is safe both ways.
+    let mut ways = BTreeSet::new();
+    for going in &going {
+        for (_, last_kept) in going.ends().filter(|&(total, _)| total == fewest) {
+            going.every_way_to(last_kept, &mut vec![], &mut |kept| {
+                let removed = (0..report.len()).filter(|index| !kept.contains(index));
+                ways.insert(removed.collect::<Vec<_>>());
+            });
+        }
+    }
+    ways.into_iter().collect()
+}
+
+/// The fewest removals for a report going up, or down when `ascending` is false.
+struct Going<'a> {
+    report: &'a [i32],
+    removals: usize,
+    rules: &'a Rules,
+    ascending: bool,
+    /// fewest[i]: the fewest removals before level i that make the report up to it safe, with it
+    /// kept, when that is at most `removals`.
+    fewest: Vec<Option<usize>>,
+}
+
+impl<'a> Going<'a> {
+    fn new(report: &'a [i32], removals: usize, rules: &'a Rules, ascending: bool) -> Going<'a> {
+        let mut going = Going {
+            report,
+            removals,
+            rules,
+            ascending,
+            fewest: Vec::with_capacity(report.len()),
+        };
+
+        for index in 0..report.len() {
+            // Every level before it removed.
+            let mut best = (index <= removals).then_some(index);
+
+            // The previous kept level, with the ones in between removed.
+            for previous in going.window(index) {
+                if let Some(total) = going.total_keeping(previous, index) {
+                    if total <= removals && best.is_none_or(|best| total < best) {
+                        best = Some(total);
+                    }
+                }
+            }
+            going.fewest.push(best);
+        }
+        going
+    }
+
+    /// The levels before `index` that can be the previous kept one with at most `removals`
+    /// removed in between.
+    fn window(&self, index: usize) -> Range<usize> {
+        index.saturating_sub(self.removals.saturating_add(1))..index
+    }
+
+    /// The removals before `index` with `previous` as the kept level before it, if it may follow.
+    fn total_keeping(&self, previous: usize, index: usize) -> Option<usize> {
+        let step = i64::from(self.report[index]) - i64::from(self.report[previous]);
+        let before = self.fewest[previous]?;
+        self.rules
+            .allows(step, self.ascending)
+            .then_some(before + (index - previous - 1))
+    }
+
+    /// The levels that can be the previous kept one before the kept level `index` with as few
+    /// removals as `fewest` has for it, `None` for removing every level before it.
+    fn previous_kept(&self, index: usize) -> impl Iterator<Item = Option<usize>> + '_ {
+        let fewest = self.fewest[index];
+        self.window(index)
+            .filter(move |&previous| self.total_keeping(previous, index) == fewest)
+            .map(Some)
+            .chain((fewest == Some(index)).then_some(None))
+    }
+
+    /// The removals of the whole report with each level as the last kept one, the levels after
+    /// it removed too, or with every level removed, when they are at most `removals`.
+    fn ends(&self) -> impl Iterator<Item = (usize, Option<usize>)> + '_ {
+        let len = self.report.len();
+        self.fewest
+            .iter()
+            .enumerate()
+            .filter_map(move |(index, fewest)| {
+                Some((fewest.as_ref()? + (len - 1 - index), Some(index)))
+            })
+            .chain([(len, None)])
+            .filter(|&(total, _)| total <= self.removals)
+    }
+
+    /// Calls `add_way` with the kept levels of every way to keep `last_kept` with the fewest
+    /// removals before it, `kept` holding the kept levels after it.
+    fn every_way_to(
+        &self,
+        last_kept: Option<usize>,
+        kept: &mut Vec<usize>,
+        add_way: &mut impl FnMut(&[usize]),
+    ) {
+        let Some(index) = last_kept else {
+            return add_way(kept);
+        };
+        kept.push(index);
+        for previous in self.previous_kept(index) {
+            self.every_way_to(previous, kept, add_way);
+        }
+        kept.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Rng;
+
+    use super::*;
+    use crate::reference;
+    use crate::tests::{random_report, random_rules};
+
+    #[test]
+    fn the_dampener_removes_as_few_levels_as_trying_every_subset() {
+        for seed in 0..2000 {
+            let mut rng = Rng::new(seed);
+            let report = random_report(&mut rng);
+            let rules = random_rules(&mut rng);
+            let fewest = reference::fewest_removals(&report, &rules);
+
+            for removals in 0..=4 {
+                assert_eq!(
+                    fewest_removals(&report, removals, &rules),
+                    (fewest <= removals).then_some(fewest),
+                    "{:?} with {:?} and {} removals",
+                    report,
+                    rules,
+                    removals
+                );
+            }
+        }
+    }
+}
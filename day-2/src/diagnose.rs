@@ -0,0 +1,346 @@
+//! Why a report is unsafe, and which levels the Problem Dampener could remove to make it safe.
+
+use crate::dampener::{self, Rules};
+
+/// What is wrong with a step between two levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    /// The step goes the other way than the first one.
+    DirectionChange,
+    /// The levels are the same in a strict report.
+    ZeroStep,
+    /// The levels differ by less than the smallest step.
+    StepTooSmall,
+    /// The levels differ by more than the largest step.
+    StepTooLarge,
+}
+
+impl Reason {
+    pub fn describe(self, rules: &Rules) -> String {
+        match self {
+            Reason::DirectionChange => "changes direction".to_string(),
+            Reason::ZeroStep => "does not change".to_string(),
+            Reason::StepTooSmall => format!("changes by less than {}", rules.min_step),
+            Reason::StepTooLarge => format!("changes by more than {}", rules.max_step),
+        }
+    }
+}
+
+/// The first step of a report that makes it unsafe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    /// The index of the first of the two levels of the step.
+    pub index: usize,
+    pub from: i32,
+    pub to: i32,
+    pub reason: Reason,
+}
+
+/// Everything that decides whether a report is safe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    /// `None` for a safe report.
+    pub violation: Option<Violation>,
+    /// Every way for the Problem Dampener to make an unsafe report safe with the fewest
+    /// removals, as part 2 counts them, each the indices of the removed levels. Empty for a safe
+    /// report and for one that needs more than the allowed removals.
+    pub removable: Vec<Vec<usize>>,
+}
+
+impl Diagnosis {
+    pub fn is_safe(&self) -> bool {
+        self.violation.is_none()
+    }
+}
+
+/// Diagnoses `report` for a Problem Dampener that removes up to `removals` levels.
+pub fn diagnose(report: &[i32], removals: usize, rules: &Rules) -> Diagnosis {
+    let violation = first_violation(report.iter().copied(), rules);
+    let removable = match violation {
+        None => vec![],
+        Some(_) => dampener::every_levels_to_remove(report, removals, rules),
+    };
+
+    Diagnosis {
+        violation,
+        removable,
+    }
+}
+
+/// The first step that is not in the direction of the first step that changes, or not of a size
+/// the rules allow. Indices count the levels as `levels` yields them.
+pub fn first_violation(levels: impl IntoIterator<Item = i32>, rules: &Rules) -> Option<Violation> {
+    let mut levels = levels.into_iter();
+    let mut previous = levels.next()?;
+    let mut ascending = None;
+
+    for (index, level) in levels.enumerate() {
+        let step = i64::from(level) - i64::from(previous);
+        let reason = if step.unsigned_abs() > u64::from(rules.max_step) {
+            Some(Reason::StepTooLarge)
+        } else if step == 0 {
+            rules.strict.then_some(Reason::ZeroStep)
+        } else if step.unsigned_abs() < u64::from(rules.min_step) {
+            Some(Reason::StepTooSmall)
+        } else if *ascending.get_or_insert(step > 0) != (step > 0) {
+            Some(Reason::DirectionChange)
+        } else {
+            None
+        };
+
+        if let Some(reason) = reason {
+            return Some(Violation {
+                index,
+                from: previous,
+                to: level,
+                reason,
+            });
+        }
+        previous = level;
+    }
+
+    None
+}
+
+/// One line per report, the report followed by its diagnosis, with levels counted from 1.
+pub fn explain(reports: &[Vec<i32>], removals: usize, rules: &Rules) -> String {
+    let mut lines = vec![];
+
+    for report in reports {
+        let levels = report
+            .iter()
+            .map(i32::to_string)
+            .collect::<Vec<_>>()
+            .join(" ");
+        let diagnosis = diagnose(report, removals, rules);
+        let Some(violation) = diagnosis.violation else {
+            lines.push(format!("{}: safe", levels));
+            continue;
+        };
+
+        let removable = match diagnosis.removable.as_slice() {
+            [] if removals == 0 => "no level may be removed".to_string(),
+            [] if removals == 1 => "no level can be removed to make it safe".to_string(),
+            [] => format!("no {} levels can be removed to make it safe", removals),
+            // One level each, as in the puzzle: "safe without level 2 or 3".
+            ways if ways.iter().all(|way| way.len() == 1) => format!(
+                "safe without level {}",
+                list(
+                    ways.iter().map(|way| (way[0] + 1).to_string()).collect(),
+                    "or"
+                )
+            ),
+            ways => format!(
+                "safe without {}",
+                list(ways.iter().map(|way| removed_levels(way)).collect(), "or")
+            ),
+        };
+        lines.push(format!(
+            "{}: unsafe, {} to {} at levels {} and {} {}, {}",
+            levels,
+            violation.from,
+            violation.to,
+            violation.index + 1,
+            violation.index + 2,
+            violation.reason.describe(rules),
+            removable
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// The levels of `way`, counted from 1, like "levels 2 and 3".
+fn removed_levels(way: &[usize]) -> String {
+    let levels = way.iter().map(|index| (index + 1).to_string()).collect();
+    match way {
+        [_] => format!("level {}", list(levels, "and")),
+        _ => format!("levels {}", list(levels, "and")),
+    }
+}
+
+/// `items` like "1, 2 and 3", with `last` before the last one.
+fn list(mut items: Vec<String>, last: &str) -> String {
+    match items.pop() {
+        None => String::new(),
+        Some(final_item) if items.is_empty() => final_item,
+        Some(final_item) => format!("{} {} {}", items.join(", "), last, final_item),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use aoc_common::Rng;
+
+    use super::*;
+    use crate::reference;
+    use crate::tests::{random_report, random_rules};
+
+    fn violation(index: usize, from: i32, to: i32, reason: Reason) -> Option<Violation> {
+        Some(Violation {
+            index,
+            from,
+            to,
+            reason,
+        })
+    }
+
+    #[test]
+    fn the_first_unsafe_step_is_reported_with_its_reason() {
+        let cases = [
+            (vec![7, 6, 4, 2, 1], None),
+            (
+                vec![1, 2, 7, 8, 9],
+                violation(1, 2, 7, Reason::StepTooLarge),
+            ),
+            (
+                vec![1, 3, 2, 4, 5],
+                violation(1, 3, 2, Reason::DirectionChange),
+            ),
+            (vec![8, 6, 4, 4, 1], violation(2, 4, 4, Reason::ZeroStep)),
+            (vec![5, 5, 9], violation(0, 5, 5, Reason::ZeroStep)),
+            (vec![1, 2, 3, -1], violation(2, 3, -1, Reason::StepTooLarge)),
+            (vec![], None),
+            (vec![4], None),
+        ];
+
+        for (report, expected) in cases {
+            assert_eq!(
+                diagnose(&report, 1, &Rules::PUZZLE).violation,
+                expected,
+                "{:?}",
+                report
+            );
+        }
+    }
+
+    #[test]
+    fn a_step_too_large_wins_over_the_other_reasons() {
+        let rules = Rules {
+            min_step: 2,
+            max_step: 3,
+            strict: true,
+        };
+        // Down by more than 3 after going up.
+        assert_eq!(
+            first_violation([1, 3, -2], &rules),
+            violation(1, 3, -2, Reason::StepTooLarge)
+        );
+        // Down by 4 as the first step, which has no direction to change from.
+        assert_eq!(
+            first_violation([5, 1], &rules),
+            violation(0, 5, 1, Reason::StepTooLarge)
+        );
+    }
+
+    #[test]
+    fn a_zero_step_wins_over_a_step_too_small() {
+        let rules = Rules {
+            min_step: 2,
+            max_step: 3,
+            strict: true,
+        };
+        assert_eq!(
+            first_violation([1, 3, 3], &rules),
+            violation(1, 3, 3, Reason::ZeroStep)
+        );
+
+        // Without strict rules the levels may stay the same, and neither is reported.
+        let loose = Rules {
+            strict: false,
+            ..rules
+        };
+        assert_eq!(first_violation([1, 3, 3, 5], &loose), None);
+    }
+
+    #[test]
+    fn a_step_too_small_wins_over_a_direction_change() {
+        let rules = Rules {
+            min_step: 2,
+            max_step: 3,
+            strict: true,
+        };
+        // Down by 1 after going up.
+        assert_eq!(
+            first_violation([1, 3, 2], &rules),
+            violation(1, 3, 2, Reason::StepTooSmall)
+        );
+        // Down by 2 after going up is only a change of direction.
+        assert_eq!(
+            first_violation([1, 3, 1], &rules),
+            violation(1, 3, 1, Reason::DirectionChange)
+        );
+    }
+
+    #[test]
+    fn every_level_that_makes_the_report_safe_is_removable() {
+        let diagnosis = diagnose(&[1, 3, 2, 4, 5], 1, &Rules::PUZZLE);
+        assert_eq!(diagnosis.removable, vec![vec![1], vec![2]]);
+        assert_eq!(
+            explain(&[vec![1, 3, 2, 4, 5]], 1, &Rules::PUZZLE),
+            "1 3 2 4 5: unsafe, 3 to 2 at levels 2 and 3 changes direction, safe without level 2 or 3"
+        );
+
+        let diagnosis = diagnose(&[1, 2, 1, 3, 3], 2, &Rules::PUZZLE);
+        assert_eq!(diagnosis.removable, vec![vec![2, 3], vec![2, 4]]);
+        assert_eq!(
+            explain(&[vec![1, 2, 1, 3, 3]], 2, &Rules::PUZZLE),
+            "1 2 1 3 3: unsafe, 2 to 1 at levels 2 and 3 changes direction, safe without levels 3 \
+             and 4 or levels 3 and 5"
+        );
+    }
+
+    #[test]
+    fn removable_levels_are_every_subset_of_the_fewest() {
+        for seed in 0..500 {
+            let mut rng = Rng::new(seed);
+            let report = random_report(&mut rng);
+            let rules = random_rules(&mut rng);
+            let fewest = reference::fewest_removals(&report, &rules);
+            let every_fewest = (0..1u32 << report.len())
+                .filter(|removed| removed.count_ones() as usize == fewest)
+                .map(|removed| {
+                    (0..report.len())
+                        .filter(|index| removed & (1 << index) != 0)
+                        .collect::<Vec<_>>()
+                })
+                .filter(|removed| {
+                    let kept = (0..report.len())
+                        .filter(|index| !removed.contains(index))
+                        .map(|index| report[index])
+                        .collect::<Vec<_>>();
+                    reference::is_safe(&kept, &rules)
+                })
+                .collect::<BTreeSet<_>>();
+
+            for removals in 0..=3 {
+                let diagnosis = diagnose(&report, removals, &rules);
+                assert_eq!(
+                    diagnosis.is_safe(),
+                    reference::is_safe(&report, &rules),
+                    "{:?}",
+                    report
+                );
+                if diagnosis.is_safe() || fewest > removals {
+                    assert_eq!(
+                        diagnosis.removable,
+                        Vec::<Vec<usize>>::new(),
+                        "{:?}",
+                        report
+                    );
+                    continue;
+                }
+
+                assert_eq!(
+                    diagnosis.removable.into_iter().collect::<BTreeSet<_>>(),
+                    every_fewest,
+                    "{:?} with {:?}",
+                    report,
+                    rules
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,193 @@
+use aoc_common::{parse, Answer, Header, Override, Param, ParseError, Solution};
+
+use dampener::Rules;
+
+pub mod dampener;
+pub mod diagnose;
+mod reference;
+
+pub struct Day2;
+
+/// The puzzle parameters, see [`Day2::PARAMS`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Params {
+    pub rules: Rules,
+    /// How many levels the Problem Dampener may remove in part 2.
+    pub part2_removals: usize,
+}
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const INPUT_PATH: &'static str = "day-2/input.txt";
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "min_step",
+            doc: "the smallest change between two levels of a safe report",
+            default: "1",
+        },
+        Param {
+            name: "max_step",
+            doc: "the largest change between two levels of a safe report",
+            default: "3",
+        },
+        Param {
+            name: "strict",
+            doc: "whether a safe report has to keep changing, or may also stay at a level",
+            default: "true",
+        },
+        Param {
+            name: "part2_removals",
+            doc: "how many levels the Problem Dampener may remove in part 2",
+            default: "1",
+        },
+    ];
+
+    type Input = (Vec<Vec<i32>>, Params);
+
+    fn parse_with(input: &str, overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        let (header, reports) = Header::split(input, Self::PARAMS, overrides)?;
+        let rules = Rules {
+            min_step: header.number("min_step")?,
+            max_step: header.number("max_step")?,
+            strict: header.get_with("strict", |value| {
+                value
+                    .parse()
+                    .map_err(|_| "expected true or false".to_string())
+            })?,
+        };
+        if rules.min_step > rules.max_step {
+            // One of them is set by the header or the command line, and only the header has a
+            // place in the input to point at.
+            let step = header
+                .value_in_input("max_step")
+                .or_else(|| header.value_in_input("min_step"))
+                .unwrap_or(&input[..0]);
+            return Err(ParseError::invalid(
+                input,
+                step,
+                format!(
+                    "min_step {} is larger than max_step {}",
+                    rules.min_step, rules.max_step
+                ),
+            )
+            .into());
+        }
+        let params = Params {
+            rules,
+            part2_removals: header.number("part2_removals")?,
+        };
+        Ok((parse_reports(input, reports)?, params))
+    }
+
+    fn part1((reports, params): &Self::Input) -> aoc_common::Result<Answer> {
+        Ok(count_safe(reports, 0, &params.rules).into())
+    }
+
+    fn part2((reports, params): &Self::Input) -> aoc_common::Result<Answer> {
+        Ok(count_safe(reports, params.part2_removals, &params.rules).into())
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part2(input))
+    }
+
+    fn explain((reports, params): &Self::Input) -> Option<String> {
+        Some(diagnose::explain(
+            reports,
+            params.part2_removals,
+            &params.rules,
+        ))
+    }
+}
+
+fn count_safe(reports: &[Vec<i32>], removals: usize, rules: &Rules) -> usize {
+    reports
+        .iter()
+        .filter(|report| dampener::is_safe_after_removing(report, removals, rules))
+        .count()
+}
+
+/// `reports` is the part of `input` after the header.
+fn parse_reports(input: &str, reports: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    reports
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|token| parse::number::<i32>(input, token))
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Rng;
+
+    use super::*;
+
+    /// Up to 8 levels from 0 to 12, so that every subset of them can be tried.
+    pub fn random_report(rng: &mut Rng) -> Vec<i32> {
+        (0..rng.between(0, 9))
+            .map(|_| rng.between(0, 12) as i32)
+            .collect()
+    }
+
+    pub fn random_rules(rng: &mut Rng) -> Rules {
+        let min_step = rng.between(0, 3) as u32;
+        Rules {
+            min_step,
+            max_step: min_step + rng.between(0, 3) as u32,
+            strict: rng.chance(1, 2),
+        }
+    }
+
+    #[test]
+    fn the_explanation_removes_as_many_levels_as_part_2() {
+        let example = aoc_common::read_to_string("test-inputs/2.txt").unwrap();
+        let input = format!("part2_removals=2\n{}", example);
+        let explanation = Day2::explain(&Day2::parse(&input).unwrap()).unwrap();
+
+        assert!(
+            explanation.contains("1 2 7 8 9: unsafe, 2 to 7 at levels 2 and 3 changes by more than 3, safe without levels 1 and 2"),
+            "{}",
+            explanation
+        );
+        let safe = explanation
+            .lines()
+            .filter(|line| !line.ends_with("can be removed to make it safe"))
+            .count();
+        assert_eq!(
+            safe.to_string(),
+            Day2::solve_part2(&input).unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn parameters_change_the_rules() {
+        let example = aoc_common::read_to_string("test-inputs/2.txt").unwrap();
+        let answer = |input: &str| Day2::solve_part2(input).unwrap().to_string();
+
+        assert_eq!(answer(&example), "4");
+        assert_eq!(answer(&format!("part2_removals=2\n{}", example)), "6");
+        assert_eq!(answer(&format!("max_step=4\n{}", example)), "5");
+        assert_eq!(
+            answer(&format!("strict=false\npart2_removals=0\n{}", example)),
+            "3"
+        );
+
+        // The error points at the step the header sets, the larger one if it sets both.
+        let line_of_error = |input: &str| match Day2::parse(input) {
+            Err(aoc_common::Error::Parse(error)) => error.location().line,
+            other => panic!("expected a parse error, got {:?}", other.map(|_| ())),
+        };
+        assert_eq!(line_of_error(&format!("min_step=4\n{}", example)), 1);
+        assert_eq!(
+            line_of_error(&format!("min_step=4\nmax_step=2\n{}", example)),
+            2
+        );
+    }
+}
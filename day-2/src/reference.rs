@@ -0,0 +1,69 @@
+//! Slow but obviously correct solutions, checked against the fast ones by `aoc check`.
+
+use aoc_common::{Answer, Solution};
+
+use crate::dampener::Rules;
+use crate::Day2;
+
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    let (reports, params) = Day2::parse(input)?;
+
+    Ok(reports
+        .iter()
+        .filter(|report| is_safe(report, &params.rules))
+        .count()
+        .into())
+}
+
+/// Tries removing every set of up to `part2_removals` levels, and none.
+pub fn part2(input: &str) -> aoc_common::Result<Answer> {
+    let (reports, params) = Day2::parse(input)?;
+
+    let safe = reports.iter().filter(|report| {
+        is_safe_after_removing(report.to_vec(), 0, params.part2_removals, &params.rules)
+    });
+    Ok(safe.count().into())
+}
+
+/// Whether `report` is safe, or is after removing up to `removals` of its levels from `from` on.
+fn is_safe_after_removing(report: Vec<i32>, from: usize, removals: usize, rules: &Rules) -> bool {
+    if is_safe(&report, rules) {
+        return true;
+    }
+    removals > 0
+        && (from..report.len()).any(|removed| {
+            let mut shorter = report.clone();
+            shorter.remove(removed);
+            is_safe_after_removing(shorter, removed, removals - 1, rules)
+        })
+}
+
+/// Every step goes up, or every step goes down, by `min_step` to `max_step`. Unless the rules
+/// are strict, levels may also stay the same.
+pub fn is_safe(report: &[i32], rules: &Rules) -> bool {
+    let steps = report
+        .windows(2)
+        .map(|pair| i64::from(pair[1]) - i64::from(pair[0]))
+        .collect::<Vec<_>>();
+    let sizes = i64::from(rules.min_step)..=i64::from(rules.max_step);
+    let allowed = |step: i64, sign: i64| {
+        (step == 0 && !rules.strict) || (step.signum() == sign && sizes.contains(&step.abs()))
+    };
+
+    steps.iter().all(|&step| allowed(step, 1)) || steps.iter().all(|&step| allowed(step, -1))
+}
+
+/// The fewest removals that make `report` safe, found by keeping every subset of its levels.
+#[cfg(test)]
+pub fn fewest_removals(report: &[i32], rules: &Rules) -> usize {
+    (0..1u32 << report.len())
+        .filter_map(|kept| {
+            let levels = (0..report.len())
+                .filter(|index| kept & (1 << index) != 0)
+                .map(|index| report[index])
+                .collect::<Vec<_>>();
+            is_safe(&levels, rules).then(|| report.len() - levels.len())
+        })
+        .min()
+        .unwrap()
+}
@@ -0,0 +1,42 @@
+//! Runs the instructions the lexer found.
+
+use crate::lexer::{Effect, Token};
+
+/// Whether the instructions are enabled, and what the enabled ones added up to so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interpreter {
+    /// Whether `don't()` turns the instructions after it off until the next `do()`, as in part 2.
+    pub with_conditionals: bool,
+    pub enabled: bool,
+    pub total: i128,
+}
+
+impl Interpreter {
+    pub fn new(with_conditionals: bool) -> Interpreter {
+        Interpreter {
+            with_conditionals,
+            enabled: true,
+            total: 0,
+        }
+    }
+
+    pub fn step(&mut self, token: &Token) {
+        match token.instruction.effect {
+            Effect::Enable => self.enabled = true,
+            Effect::Disable => self.enabled = !self.with_conditionals,
+            Effect::Value(value) => {
+                if self.enabled {
+                    self.total += value(&token.operands);
+                }
+            }
+        }
+    }
+
+    /// Runs every token in order and returns the total.
+    pub fn run(mut self, tokens: &[Token]) -> i128 {
+        for token in tokens {
+            self.step(token);
+        }
+        self.total
+    }
+}
@@ -0,0 +1,215 @@
+//! Splits corrupted memory into instructions such as `mul(2,4)`, `do()` and `don't()` in one scan,
+//! skipping everything else.
+
+use std::ops::RangeInclusive;
+
+use aoc_common::{params, parse, ParseError};
+
+/// What an instruction does when the interpreter runs it.
+#[derive(Debug, Clone, Copy)]
+pub enum Effect {
+    /// Turns the following instructions on.
+    Enable,
+    /// Turns the following instructions off, when the interpreter heeds conditionals.
+    Disable,
+    /// Adds the result of the instruction to the total.
+    Value(fn(&[i32]) -> i128),
+}
+
+/// An instruction of the form `name(operand,operand,…)`.
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    pub name: &'static str,
+    pub operands: usize,
+    pub effect: Effect,
+}
+
+pub const MUL: Instruction = Instruction {
+    name: "mul",
+    operands: 2,
+    effect: Effect::Value(|operands| i128::from(operands[0]) * i128::from(operands[1])),
+};
+
+pub const ADD: Instruction = Instruction {
+    name: "add",
+    operands: 2,
+    effect: Effect::Value(|operands| i128::from(operands[0]) + i128::from(operands[1])),
+};
+
+pub const DO: Instruction = Instruction {
+    name: "do",
+    operands: 0,
+    effect: Effect::Enable,
+};
+
+pub const DONT: Instruction = Instruction {
+    name: "don't",
+    operands: 0,
+    effect: Effect::Disable,
+};
+
+/// Every instruction the lexer can be told to look for.
+pub const INSTRUCTIONS: &[Instruction] = &[MUL, ADD, DO, DONT];
+
+/// An instruction found in the memory.
+#[derive(Debug, Clone)]
+pub struct Token {
+    /// The byte offset of its name.
+    pub offset: usize,
+    pub instruction: &'static Instruction,
+    pub operands: Vec<i32>,
+}
+
+/// What the lexer looks for.
+#[derive(Debug, Clone)]
+pub struct Lexer {
+    pub instructions: Vec<&'static Instruction>,
+    /// How many digits an operand may have. Longer or shorter numbers make the text around them
+    /// junk.
+    pub digits: RangeInclusive<usize>,
+}
+
+impl Lexer {
+    /// The instructions of the puzzle, with operands of 1 to 3 digits.
+    pub fn puzzle() -> Lexer {
+        Lexer {
+            instructions: vec![&MUL, &DO, &DONT],
+            digits: 1..=3,
+        }
+    }
+
+    /// Every instruction in `memory`, in order, with offsets into `input`. An operand that does
+    /// not fit in an `i32` is an error rather than junk.
+    ///
+    /// `memory` is the part of `input` after the header.
+    pub fn lex(&self, input: &str, memory: &str) -> Result<Vec<Token>, ParseError> {
+        let bytes = memory.as_bytes();
+        let start = input.len() - memory.len();
+        let mut tokens = vec![];
+        let mut offset = 0;
+
+        while offset < bytes.len() {
+            let mut found = None;
+            for &instruction in &self.instructions {
+                if let Some((operands, end)) =
+                    self.instruction_at(input, memory, offset, instruction)?
+                {
+                    found = Some((instruction, operands, end));
+                    break;
+                }
+            }
+
+            match found {
+                Some((instruction, operands, end)) => {
+                    tokens.push(Token {
+                        offset: start + offset,
+                        instruction,
+                        operands,
+                    });
+                    // Instructions end with `)`, so none starts inside another.
+                    offset = end;
+                }
+                None => offset += 1,
+            }
+        }
+        Ok(tokens)
+    }
+
+    /// The operands of `instruction` at `offset` and the offset just past it, if it is there.
+    fn instruction_at(
+        &self,
+        input: &str,
+        memory: &str,
+        offset: usize,
+        instruction: &Instruction,
+    ) -> Result<Option<(Vec<i32>, usize)>, ParseError> {
+        let bytes = memory.as_bytes();
+        let Some(rest) = bytes[offset..].strip_prefix(instruction.name.as_bytes()) else {
+            return Ok(None);
+        };
+        let Some(mut rest) = rest.strip_prefix(b"(") else {
+            return Ok(None);
+        };
+
+        let mut operands = Vec::with_capacity(instruction.operands);
+        for index in 0..instruction.operands {
+            if index > 0 {
+                let Some(after_comma) = rest.strip_prefix(b",") else {
+                    return Ok(None);
+                };
+                rest = after_comma;
+            }
+
+            let digits = rest.iter().take_while(|byte| byte.is_ascii_digit()).count();
+            if !self.digits.contains(&digits) {
+                return Ok(None);
+            }
+            let start = bytes.len() - rest.len();
+            operands.push(parse::number(input, &memory[start..start + digits])?);
+            rest = &rest[digits..];
+        }
+
+        match rest.strip_prefix(b")") {
+            Some(after) => Ok(Some((operands, bytes.len() - after.len()))),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Reads how many digits an operand may have, a [`params::range`] that leaves out 0.
+pub fn parse_digits(text: &str) -> Result<RangeInclusive<usize>, String> {
+    let digits = params::range(text)?;
+    if *digits.start() == 0 {
+        return Err("an operand needs at least 1 digit".to_string());
+    }
+    Ok(digits)
+}
+
+/// Reads instruction names separated by commas, e.g. `mul,do,don't`.
+pub fn parse_instructions(text: &str) -> Result<Vec<&'static Instruction>, String> {
+    text.split(',')
+        .map(|name| {
+            INSTRUCTIONS
+                .iter()
+                .find(|instruction| instruction.name == name)
+                .ok_or_else(|| {
+                    let known = INSTRUCTIONS
+                        .iter()
+                        .map(|instruction| instruction.name)
+                        .collect::<Vec<_>>();
+                    format!(
+                        "unknown instruction {:?}, expected {}",
+                        name,
+                        known.join(", ")
+                    )
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_start_at_their_offsets() {
+        let example = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let tokens = Lexer::puzzle().lex(example, example).unwrap();
+
+        let found = tokens
+            .iter()
+            .map(|token| (token.offset, token.instruction.name, token.operands.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            [
+                (1, MUL.name, vec![2, 4]),
+                (20, DONT.name, vec![]),
+                (28, MUL.name, vec![5, 5]),
+                (48, MUL.name, vec![11, 8]),
+                (59, DO.name, vec![]),
+                (64, MUL.name, vec![8, 5]),
+            ]
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use aoc_common::{Answer, Header, Override, Param, Solution};
+
+use interpreter::Interpreter;
+use lexer::{Lexer, Token};
+
+pub mod interpreter;
+pub mod lexer;
+mod reference;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const INPUT_PATH: &'static str = "day-3/input.txt";
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "instructions",
+            doc: "the instructions in the memory, any of mul, add, do and don't",
+            default: "mul,do,don't",
+        },
+        Param {
+            name: "operand_digits",
+            doc: "how many digits an operand may have, <min>..=<max> or <min>..",
+            default: "1..=3",
+        },
+    ];
+
+    type Input = Vec<Token>;
+
+    fn parse_with(input: &str, overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        let (header, memory) = Header::split(input, Self::PARAMS, overrides)?;
+        let lexer = Lexer {
+            instructions: header.get_with("instructions", lexer::parse_instructions)?,
+            digits: header.get_with("operand_digits", lexer::parse_digits)?,
+        };
+        Ok(lexer.lex(input, memory)?)
+    }
+
+    fn part1(tokens: &Self::Input) -> aoc_common::Result<Answer> {
+        Ok(Interpreter::new(false).run(tokens).into())
+    }
+
+    fn part2(tokens: &Self::Input) -> aoc_common::Result<Answer> {
+        Ok(Interpreter::new(true).run(tokens).into())
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::testing::{answer, reference};
+    use aoc_common::Rng;
+
+    use super::*;
+
+    /// Junk, pieces of instructions and whole ones.
+    fn memory(rng: &mut Rng) -> String {
+        const PIECES: &[&str] = &[
+            "x", "(", ")", ",", "'", "é", "mul", "mul(", "add(", "do", "do(", "don't", "don't(",
+            "do()", "don't()", "mul(,)", "add(1,", "do(1)",
+        ];
+
+        let mut text = String::new();
+        for _ in 0..rng.between(0, 40) {
+            if rng.chance(1, 2) {
+                text.push_str(PIECES[rng.index(PIECES.len())]);
+            } else {
+                let name = ["mul", "add"][rng.index(2)];
+                let operand = |rng: &mut Rng| {
+                    let digits = rng.between(0, 5) as usize;
+                    (0..digits)
+                        .map(|_| char::from(b'0' + rng.below(10) as u8))
+                        .collect::<String>()
+                };
+                let (first, second) = (operand(rng), operand(rng));
+                text.push_str(&format!("{}({},{})", name, first, second));
+            }
+        }
+        text
+    }
+
+    #[test]
+    fn a_dont_without_a_later_do_turns_off_the_rest() {
+        let input = "mul(2,3)don't()mul(4,5)mul(6,7)";
+        assert_eq!(answer(Day3::solve_part1, input), "68");
+        assert_eq!(answer(Day3::solve_part2, input), "6");
+    }
+
+    #[test]
+    fn disabled_instructions_do_not_hide_the_same_text_elsewhere() {
+        let input = "mul(1,1)don't()mul(1,1)do()mul(1,1)";
+        assert_eq!(answer(Day3::solve_part2, input), "2");
+    }
+
+    #[test]
+    fn parameters_choose_the_instructions_and_their_operands() {
+        let input = "add(2,3)mul(2,3)don't()add(1,1)do()mul(1000,2)";
+
+        assert_eq!(answer(Day3::solve_part2, input), "6");
+        assert_eq!(
+            answer(
+                Day3::solve_part2,
+                &format!("instructions=mul,add,do,don't\n{}", input)
+            ),
+            "11"
+        );
+        assert_eq!(
+            answer(
+                Day3::solve_part1,
+                &format!(
+                    "instructions=mul,add,do,don't\noperand_digits=1..\n{}",
+                    input
+                )
+            ),
+            "2013"
+        );
+        assert_eq!(
+            answer(Day3::solve_part2, &format!("operand_digits=1..\n{}", input)),
+            "2006"
+        );
+        assert!(Day3::parse(&format!("instructions=mul,div\n{}", input)).is_err());
+        assert!(Day3::parse(&format!("operand_digits=3..=1\n{}", input)).is_err());
+    }
+
+    #[test]
+    fn the_interpreter_agrees_with_reading_an_instruction_at_every_byte() {
+        let headers = [
+            "",
+            "operand_digits=1..=3\n",
+            "instructions=mul,add,do,don't\n",
+            "instructions=add,don't\noperand_digits=2..=4\n",
+        ];
+
+        for seed in 0..500 {
+            let mut rng = Rng::new(seed);
+            let input = format!("{}{}", headers[rng.index(headers.len())], memory(&mut rng));
+
+            assert_eq!(
+                answer(Day3::solve_part1, &input),
+                reference(Day3::reference_part1, &input),
+                "{:?}",
+                input
+            );
+            assert_eq!(
+                answer(Day3::solve_part2, &input),
+                reference(Day3::reference_part2, &input),
+                "{:?}",
+                input
+            );
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//! Slow but obviously correct solutions, checked against the fast ones by `aoc check`. They try
+//! to read an instruction at every byte instead of lexing the memory once.
+
+use std::ops::RangeInclusive;
+
+use aoc_common::{parse, Answer, Header, Solution};
+
+use crate::lexer::{self, Effect, Instruction};
+use crate::Day3;
+
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    Ok(total(input, false)?.into())
+}
+
+pub fn part2(input: &str) -> aoc_common::Result<Answer> {
+    Ok(total(input, true)?.into())
+}
+
+/// `with_conditionals` lets `don't()` turn the instructions off until the next `do()`.
+fn total(input: &str, with_conditionals: bool) -> aoc_common::Result<i128> {
+    let (header, memory) = Header::split(input, Day3::PARAMS, &[])?;
+    let instructions = header.get_with("instructions", lexer::parse_instructions)?;
+    let digits = header.get_with("operand_digits", lexer::parse_digits)?;
+
+    let mut enabled = true;
+    let mut total = 0;
+    for start in 0..memory.len() {
+        // No instruction starts inside a character.
+        let Some(text) = memory.get(start..) else {
+            continue;
+        };
+        for instruction in &instructions {
+            let Some(operands) = operands(text, instruction, &digits) else {
+                continue;
+            };
+            let operands = operands
+                .iter()
+                .map(|operand| parse::number(input, operand))
+                .collect::<Result<Vec<i32>, _>>()?;
+            match instruction.effect {
+                Effect::Enable => enabled = true,
+                Effect::Disable => enabled = !with_conditionals,
+                Effect::Value(value) if enabled => total += value(&operands),
+                Effect::Value(_) => {}
+            }
+        }
+    }
+    Ok(total)
+}
+
+/// The operands of `name(X,Y,…)` at the start of `text`, if every one has an allowed number of
+/// digits.
+fn operands<'a>(
+    text: &'a str,
+    instruction: &Instruction,
+    digits: &RangeInclusive<usize>,
+) -> Option<Vec<&'a str>> {
+    let inside = text.strip_prefix(instruction.name)?.strip_prefix('(')?;
+    let (inside, _) = inside.split_once(')')?;
+    let operands = match inside {
+        "" => vec![],
+        _ => inside.split(',').collect::<Vec<_>>(),
+    };
+
+    let allowed = |operand: &&str| {
+        digits.contains(&operand.len()) && operand.bytes().all(|byte| byte.is_ascii_digit())
+    };
+    (operands.len() == instruction.operands && operands.iter().all(allowed)).then_some(operands)
+}
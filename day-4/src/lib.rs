@@ -0,0 +1,79 @@
+use aoc_common::{Answer, Direction8, Grid, Override, Point, Solution};
+use regex::Regex;
+
+mod reference;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const INPUT_PATH: &'static str = "day-4/input.txt";
+
+    type Input = Grid<char>;
+
+    fn parse_with(input: &str, _overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part1(grid: &Self::Input) -> aoc_common::Result<Answer> {
+        // seperate regexes are necessary because find_iter only finds non-overlapping matches
+        let reg1 = Regex::new(r"(XMAS)").unwrap();
+        let reg2 = Regex::new(r"(SAMX)").unwrap();
+
+        let match_count: usize = get_orientations(grid)
+            .iter()
+            .map(|row| reg1.find_iter(row).count() + reg2.find_iter(row).count())
+            .sum();
+        Ok(match_count.into())
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Answer> {
+        let x_mas_count = input
+            .iter()
+            .filter(|entry| entry.1.eq(&'A') && check_for_mas(input, entry.0))
+            .count();
+
+        Ok(x_mas_count.into())
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part2(input))
+    }
+}
+
+/// Every horizontal, vertical and diagonal line of the grid as a string.
+fn get_orientations(grid: &Grid<char>) -> Vec<String> {
+    let hor_rows = grid.rows().map(|row| row.iter().collect::<String>());
+    let vert_rows = grid.columns().map(|column| column.collect::<String>());
+    let diag1_rows = grid
+        .anti_diagonals()
+        .map(|diagonal| diagonal.collect::<String>());
+    let diag2_rows = grid
+        .diagonals()
+        .map(|diagonal| diagonal.collect::<String>());
+
+    hor_rows
+        .chain(vert_rows)
+        .chain(diag1_rows)
+        .chain(diag2_rows)
+        .collect()
+}
+
+fn check_for_mas(input: &Grid<char>, coords: Point) -> bool {
+    let diagonal = |first: Direction8, second: Direction8| {
+        input
+            .step(coords, first)
+            .zip(input.step(coords, second))
+            .map(|(first, second)| [input[first], input[second]].iter().collect::<String>())
+            .filter(|d| d.eq("MS") || d.eq("SM"))
+    };
+
+    let diag1 = diagonal(Direction8::UpLeft, Direction8::DownRight);
+    let diag2 = diagonal(Direction8::UpRight, Direction8::DownLeft);
+
+    diag1.is_some() && diag2.is_some()
+}
@@ -0,0 +1,5 @@
+use aoc_common::OrExit;
+
+fn main() {
+    aoc_common::run_day::<day_4::Day4>(["", "'X'-MAS count: "]).or_exit();
+}
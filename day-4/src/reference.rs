@@ -0,0 +1,59 @@
+//! Slow but obviously correct solutions, checked against the fast ones by `aoc check`.
+
+use aoc_common::{Answer, Point, Solution};
+
+use crate::Day4;
+
+/// Every way to walk from one letter in a straight line, horizontally, vertically or diagonally.
+const DIRECTIONS: [(i64, i64); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+
+/// Tries to spell XMAS from every letter in every direction.
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    let grid = Day4::parse(input)?;
+
+    let mut count = 0u64;
+    for Point { x, y } in grid.positions() {
+        for (dx, dy) in DIRECTIONS {
+            let spelled = (0..4)
+                .map(|step| grid.get_signed((x as i64 + dx * step, y as i64 + dy * step)))
+                .collect::<Option<String>>();
+            if spelled.as_deref() == Some("XMAS") {
+                count += 1;
+            }
+        }
+    }
+    Ok(count.into())
+}
+
+/// Reads both diagonals through every A.
+pub fn part2(input: &str) -> aoc_common::Result<Answer> {
+    let grid = Day4::parse(input)?;
+    let at = |x: usize, y: usize, dx: i64, dy: i64| grid.get_signed((x as i64 + dx, y as i64 + dy));
+
+    let mut count = 0u64;
+    for Point { x, y } in grid.positions() {
+        if grid[(x, y)] != 'A' {
+            continue;
+        }
+
+        let diagonals = [[(-1, -1), (1, 1)], [(1, -1), (-1, 1)]].map(|ends| {
+            let letters = ends
+                .map(|(dx, dy)| at(x, y, dx, dy).copied())
+                .map(|letter| letter.unwrap_or('.'));
+            letters == ['M', 'S'] || letters == ['S', 'M']
+        });
+        if diagonals == [true, true] {
+            count += 1;
+        }
+    }
+    Ok(count.into())
+}
@@ -0,0 +1,176 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{Answer, Error, Override, ParseError, Solution, parse};
+
+mod reference;
+
+pub struct Day5;
+
+/// The pages that have to come after each page.
+type RuleMap = HashMap<i64, HashSet<i64>>;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const INPUT_PATH: &'static str = "day-5/input.txt";
+
+    type Input = (RuleMap, Updates);
+
+    fn parse_with(input: &str, _overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        let (input_rules, input_updates) = parse_input(input)?;
+
+        Ok((build_rule_map(input_rules), input_updates))
+    }
+
+    fn part1((rule_map, input_updates): &Self::Input) -> aoc_common::Result<Answer> {
+        let valid_updates = input_updates
+            .iter()
+            .filter(|update_vec| validate(update_vec, rule_map))
+            .collect::<Vec<_>>();
+
+        let middle_sum = valid_updates
+            .iter()
+            .flat_map(|v| v.get(v.len() / 2))
+            .sum::<i64>();
+
+        Ok(middle_sum.into())
+    }
+
+    fn part2((rule_map, input_updates): &Self::Input) -> aoc_common::Result<Answer> {
+        let invalid_updates = input_updates
+            .iter()
+            .filter(|update_vec| !validate(update_vec, rule_map))
+            .collect::<Vec<_>>();
+
+        let rearranged = invalid_updates
+            .iter()
+            .map(|inv_vec| {
+                rearrange(inv_vec, rule_map).ok_or_else(|| {
+                    Error::NoAnswer(format!(
+                        "the ordering rules for update {:?} contain a cycle",
+                        inv_vec
+                    ))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let middle_sum = rearranged
+            .iter()
+            .flat_map(|v| v.get(v.len() / 2).cloned())
+            .sum::<i64>();
+
+        Ok(middle_sum.into())
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part2(input))
+    }
+}
+
+fn validate(update_inputs: &[i64], rule_map: &RuleMap) -> bool {
+    for (ind, val) in update_inputs.iter().enumerate() {
+        let (before_part, _after_part) = update_inputs.split_at(ind + 1);
+
+        let rule_set_opt = rule_map.get(val);
+        let invalid = rule_set_opt.map(|rule_set| {
+            before_part
+                .iter()
+                .any(|val_in_before_part| rule_set.contains(val_in_before_part))
+        });
+
+        if invalid.unwrap_or(false) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Returns `None` when the rules for the pages of the update form a cycle.
+fn rearrange(update_row: &[i64], rule_map: &RuleMap) -> Option<Vec<i64>> {
+    let mut unsorted: HashSet<i64> = HashSet::new();
+    let mut sorted = vec![];
+
+    for &page in update_row {
+        unsorted.insert(page);
+    }
+
+    while !unsorted.is_empty() {
+        let right_most_pages = unsorted
+            .iter()
+            .filter(|key| {
+                let entry = match rule_map.get(key) {
+                    Some(ent) => ent,
+                    None => return true,
+                };
+                let is_right_most = unsorted.iter().all(|page| !entry.contains(page));
+                is_right_most
+            })
+            .copied()
+            .collect::<Vec<_>>();
+
+        if right_most_pages.is_empty() {
+            return None;
+        }
+
+        for page in right_most_pages {
+            unsorted.remove(&page);
+            sorted.push(page);
+        }
+    }
+
+    sorted.reverse();
+    Some(sorted)
+}
+
+fn build_rule_map(rules: Vec<[i64; 2]>) -> RuleMap {
+    let mut ret: RuleMap = HashMap::new();
+    for [before, after] in rules {
+        ret.entry(before)
+            .and_modify(|after_vals| {
+                after_vals.insert(after);
+            })
+            .or_insert(HashSet::from([after]));
+    }
+
+    ret
+}
+
+type Rules = Vec<[i64; 2]>;
+type Updates = Vec<Vec<i64>>;
+
+fn parse_input(input: &str) -> Result<(Rules, Updates), ParseError> {
+    let mut parsed_rules = vec![];
+    let mut parsed_updates = vec![];
+
+    for line in input.lines() {
+        if line.contains('|') {
+            let mut pages = line.split('|');
+            let mut next_page = || match pages.next() {
+                Some(page) => parse::number::<i64>(input, page),
+                None => Err(ParseError::unexpected_end_of(input, line, "a page number")),
+            };
+            let rule = [next_page()?, next_page()?];
+
+            if let Some(extra) = pages.next() {
+                return Err(ParseError::unexpected(
+                    input,
+                    extra,
+                    "a rule of two page numbers",
+                ));
+            }
+            parsed_rules.push(rule);
+        } else if !line.is_empty() {
+            let update = line
+                .split(',')
+                .map(|page| parse::number::<i64>(input, page))
+                .collect::<Result<Vec<_>, _>>()?;
+            parsed_updates.push(update);
+        }
+    }
+
+    Ok((parsed_rules, parsed_updates))
+}
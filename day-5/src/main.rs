@@ -0,0 +1,5 @@
+use aoc_common::OrExit;
+
+fn main() {
+    aoc_common::run_day::<day_5::Day5>(["Middle sum: ", "Middle sum: "]).or_exit();
+}
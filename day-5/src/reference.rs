@@ -0,0 +1,43 @@
+//! Slow but obviously correct solutions, checked against the fast ones by `aoc check`.
+
+use aoc_common::{Answer, Error};
+
+use crate::parse_input;
+
+/// An update is in order when no rule puts a later page before an earlier one.
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    let (rules, updates) = parse_input(input)?;
+
+    let total: i64 = updates
+        .iter()
+        .filter(|update| in_order(update, &rules))
+        .map(|update| update[update.len() / 2])
+        .sum();
+    Ok(total.into())
+}
+
+/// In the correct order, the middle page is the one with half of the other pages ruled before it.
+pub fn part2(input: &str) -> aoc_common::Result<Answer> {
+    let (rules, updates) = parse_input(input)?;
+
+    let mut total = 0;
+    for update in updates.iter().filter(|update| !in_order(update, &rules)) {
+        let middle = update.iter().find(|&&page| {
+            let before = update
+                .iter()
+                .filter(|&&other| rules.contains(&[other, page]))
+                .count();
+            before == update.len() / 2
+        });
+        total += middle.ok_or_else(|| {
+            Error::NoAnswer(format!("the rules do not order update {:?}", update))
+        })?;
+    }
+    Ok(total.into())
+}
+
+fn in_order(update: &[i64], rules: &[[i64; 2]]) -> bool {
+    (0..update.len()).all(|first| {
+        (first + 1..update.len()).all(|later| !rules.contains(&[update[later], update[first]]))
+    })
+}
@@ -0,0 +1,222 @@
+use std::collections::HashSet;
+
+use aoc_common::{
+    Answer, Direction, Error, Grid, Override, ParseError, Point, Solution, drawing, parse, trace,
+};
+#[cfg(feature = "parallel")]
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+mod reference;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const INPUT_PATH: &'static str = "day-6/input.txt";
+
+    type Input = Grid<char>;
+
+    fn parse_with(input: &str, _overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        Ok(parse_map(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Answer> {
+        match EnvMap::new(input.clone()).run() {
+            (last, EndState::GuardOffTheMap) => Ok(last.count_x().into()),
+            (_, EndState::Loop) => Err(Error::NoAnswer(
+                "the guard walks in a loop and never leaves the map".to_string(),
+            )),
+        }
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Answer> {
+        let start_state = EnvMap::new(input.clone());
+        let Some(start @ (guard, _)) = start_state.guard else {
+            return Ok(0.into());
+        };
+        let (walked, end_state) = start_state.run();
+
+        // An obstruction off the guard's path leaves the walk as it is, so only the tiles the
+        // guard walks over need a walk of their own.
+        let (on_path, off_path): (Vec<Point>, Vec<Point>) = input
+            .positions()
+            .filter(|&position| position != guard && input[position] != '#')
+            .partition(|&position| walked.data[position] != '.');
+        let off_path_loops = match end_state {
+            EndState::Loop => off_path.len(),
+            EndState::GuardOffTheMap => 0,
+        };
+
+        // Each thread reuses its own record of the turns, the walks never change the map.
+        let new_turns = || Turns::new(input);
+        #[cfg(feature = "parallel")]
+        let loop_count = on_path
+            .par_iter()
+            .map_init(new_turns, |turns, &position| {
+                turns.loops_with_obstruction(input, start, position)
+            })
+            .filter(|&loops| loops)
+            .count();
+        #[cfg(not(feature = "parallel"))]
+        let loop_count = {
+            let mut turns = new_turns();
+            on_path
+                .iter()
+                .filter(|&&position| turns.loops_with_obstruction(input, start, position))
+                .count()
+        };
+        Ok((loop_count + off_path_loops).into())
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part2(input))
+    }
+}
+
+fn parse_map(input: &str) -> Result<Grid<char>, ParseError> {
+    let map = Grid::parse(input)?;
+    parse::expect_chars(
+        input,
+        input,
+        ".#^>v<",
+        "'.', '#' or a guard facing '^', '>', 'v' or '<'",
+    )?;
+
+    let mut guards = input.matches(['^', '>', 'v', '<']);
+    match (guards.next(), guards.next()) {
+        (None, _) => Err(ParseError::unexpected_end(
+            input,
+            "a guard facing '^', '>', 'v' or '<'",
+        )),
+        (Some(_), Some(second)) => Err(ParseError::invalid(
+            input,
+            second,
+            "the map can only have one guard",
+        )),
+        (Some(_), None) => Ok(map),
+    }
+}
+
+#[derive(Debug, Clone)]
+struct EnvMap {
+    data: Grid<char>,
+    /// Where the guard is and which way it faces, `None` once it left the map.
+    guard: Option<(Point, Direction)>,
+    history: HashSet<(Point, Direction)>,
+}
+
+/// Where and which way the guard turned during a walk, for finding loops without a copy of the
+/// map or a set per walk. A tile counts as turned at in the walks numbered `walk` only, so the
+/// record does not need to be cleared between walks.
+struct Turns {
+    walk: u32,
+    turned: Grid<[u32; 4]>,
+}
+
+impl Turns {
+    fn new(map: &Grid<char>) -> Turns {
+        Turns {
+            walk: 0,
+            turned: Grid::new(map.width(), map.height(), [0; 4]),
+        }
+    }
+
+    /// Whether the guard walks into a loop on `map` with an obstruction at `obstruction`. A
+    /// guard that turns twice at the same tile facing the same way walks in a loop.
+    fn loops_with_obstruction(
+        &mut self,
+        map: &Grid<char>,
+        (mut guard, mut dir): (Point, Direction),
+        obstruction: Point,
+    ) -> bool {
+        self.walk += 1;
+        while let Some(next) = map.step(guard, dir) {
+            if next != obstruction && map[next] != '#' {
+                guard = next;
+                continue;
+            }
+            dir = dir.turn_right();
+            let turned = &mut self.turned[guard][dir as usize];
+            if *turned == self.walk {
+                return true;
+            }
+            *turned = self.walk;
+        }
+        false
+    }
+}
+
+#[derive(Eq, PartialEq, Debug, Clone)]
+enum EndState {
+    Loop,
+    GuardOffTheMap,
+}
+
+impl EnvMap {
+    fn new(data: Grid<char>) -> Self {
+        let guard = find_guard(&data);
+        let history = HashSet::new();
+        EnvMap {
+            data,
+            guard,
+            history,
+        }
+    }
+
+    fn is_blocked(&self, guard: Point, dir: Direction) -> bool {
+        self.data
+            .step(guard, dir)
+            .filter(|&next| self.data[next].eq(&'#'))
+            .is_some()
+    }
+
+    /// Turns the guard or moves it one step, marking the tile it leaves with `'X'`.
+    fn move_guard(&mut self) -> Result<(), EndState> {
+        let (guard, dir) = self.guard.ok_or(EndState::GuardOffTheMap)?;
+        self.data[guard] = 'X';
+
+        if self.is_blocked(guard, dir) {
+            let new_dir = dir.turn_right();
+            self.data[guard] = new_dir.arrow();
+            self.guard = Some((guard, new_dir));
+            if !self.history.insert((guard, new_dir)) {
+                return Err(EndState::Loop);
+            }
+            return Ok(());
+        }
+
+        self.guard = self.data.step(guard, dir).map(|next| (next, dir));
+        if let Some((next, dir)) = self.guard {
+            self.data[next] = dir.arrow();
+            if !self.history.insert((next, dir)) {
+                return Err(EndState::Loop);
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves the guard until it walks off the map or into a loop. Returns the last map with the
+    /// visited tiles marked and how the walk ended.
+    fn run(mut self) -> (EnvMap, EndState) {
+        loop {
+            if let Err(end_state) = self.move_guard() {
+                trace!("walk ended in {:?}{}", end_state, drawing!(self.data));
+                return (self, end_state);
+            }
+        }
+    }
+
+    fn count_x(&self) -> usize {
+        self.data.iter().filter(|(_, c)| c.eq(&&'X')).count()
+    }
+}
+
+/// Where the guard is and which way it faces.
+fn find_guard(data: &Grid<char>) -> Option<(Point, Direction)> {
+    let guard = data.find(|c| ['^', '>', 'v', '<'].contains(c))?;
+    Some((guard, Direction::from_arrow(data[guard])?))
+}
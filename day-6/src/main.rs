@@ -0,0 +1,5 @@
+use aoc_common::OrExit;
+
+fn main() {
+    aoc_common::run_day::<day_6::Day6>(["total Xs: ", "total loop permutations: "]).or_exit();
+}
@@ -0,0 +1,62 @@
+//! Slow but obviously correct solutions, checked against the fast ones by `aoc check`. They walk
+//! the guard one step at a time and call it a loop once the guard has taken more steps than
+//! there are distinct positions and headings.
+
+use std::collections::HashSet;
+
+use aoc_common::{Answer, Error, Grid, Solution};
+
+use crate::Day6;
+
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    let map = Day6::parse(input)?;
+
+    let visited = walk(&map).ok_or_else(|| {
+        Error::NoAnswer("the guard walks in a loop and never leaves the map".to_string())
+    })?;
+    Ok(visited.len().into())
+}
+
+/// Tries an obstruction on every open position except the guard's.
+pub fn part2(input: &str) -> aoc_common::Result<Answer> {
+    let map = Day6::parse(input)?;
+
+    let mut loops = 0u64;
+    for position in map.positions() {
+        if map[position] != '.' {
+            continue;
+        }
+        let mut obstructed = map.clone();
+        obstructed.set(position, '#');
+        if walk(&obstructed).is_none() {
+            loops += 1;
+        }
+    }
+    Ok(loops.into())
+}
+
+/// The positions the guard visits before leaving the map, `None` when the guard never leaves.
+fn walk(map: &Grid<char>) -> Option<HashSet<(i64, i64)>> {
+    let guard = map
+        .find(|tile| "^>v<".contains(*tile))
+        .expect("parsing checks that there is a guard");
+    let (mut x, mut y) = (guard.x as i64, guard.y as i64);
+    let (mut dx, mut dy) = match map[guard] {
+        '^' => (0, -1),
+        '>' => (1, 0),
+        'v' => (0, 1),
+        _ => (-1, 0),
+    };
+
+    let mut visited = HashSet::new();
+    for _ in 0..=4 * map.width() * map.height() {
+        visited.insert((x, y));
+        match map.get_signed((x + dx, y + dy)) {
+            None => return Some(visited),
+            // Turning right, with y growing downwards.
+            Some('#') => (dx, dy) = (-dy, dx),
+            Some(_) => (x, y) = (x + dx, y + dy),
+        }
+    }
+    None
+}
@@ -0,0 +1,157 @@
+use aoc_common::{Answer, Override, ParseError, Solution, parse};
+
+mod reference;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const INPUT_PATH: &'static str = "day-7/input.txt";
+
+    type Input = Vec<(i64, Vec<i64>)>;
+
+    fn parse_with(input: &str, _overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Answer> {
+        Ok(calibration_result(input, false).into())
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Answer> {
+        Ok(calibration_result(input, true).into())
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part2(input))
+    }
+}
+
+fn calibration_result(input: &[(i64, Vec<i64>)], with_concat: bool) -> i64 {
+    input
+        .iter()
+        .flat_map(|(result, values)| solve_equation(*result, values.clone(), with_concat))
+        .map(|solve_res| solve_res.0)
+        .sum()
+}
+
+#[derive(Clone)]
+enum Operation {
+    Add,
+    Multiply,
+    UnConcat,
+}
+
+/// `with_concat` allows the concatenation operator of part 2 besides adding and multiplying.
+fn solve_equation(
+    result: i64,
+    values: Vec<i64>,
+    with_concat: bool,
+) -> Option<(i64, Vec<i64>, Vec<Operation>)> {
+    let mut rev = values.clone();
+    rev.reverse();
+
+    let res = solve_inner(result, rev, vec![], with_concat);
+    Some((result, values, res?))
+}
+
+fn solve_inner(
+    result: i64,
+    values: Vec<i64>,
+    answer: Vec<Operation>,
+    with_concat: bool,
+) -> Option<Vec<Operation>> {
+    if result == 0 && values.iter().all(|value| *value == 0) {
+        return Some(answer);
+    }
+
+    if result <= 0 {
+        return None;
+    }
+
+    let (head, tail) = values.split_first()?;
+
+    with_concat
+        .then(|| unconcat(result, *head))
+        .flatten()
+        .and_then(|unconcatted| {
+            solve_inner(
+                unconcatted,
+                tail.to_vec(),
+                [vec![Operation::UnConcat], answer.clone()].concat(),
+                with_concat,
+            )
+        })
+        .or_else(|| {
+            if result.checked_rem(*head) != Some(0) {
+                return None;
+            }
+            solve_inner(
+                result / head,
+                tail.to_vec(),
+                [vec![Operation::Multiply], answer.clone()].concat(),
+                with_concat,
+            )
+        })
+        .or_else(|| {
+            solve_inner(
+                result - head,
+                tail.to_vec(),
+                [vec![Operation::Add], answer.clone()].concat(),
+                with_concat,
+            )
+        })
+}
+
+fn unconcat(result: i64, value: i64) -> Option<i64> {
+    let res_str = result.to_string();
+    let val_str = value.to_string();
+    if val_str.len() > res_str.len() {
+        return None;
+    }
+    let split = res_str.split_at(res_str.len() - val_str.len());
+    if split.1.eq(&val_str) {
+        return split.0.parse::<i64>().ok();
+    }
+    None
+}
+
+fn parse_input(input: &str) -> Result<Vec<(i64, Vec<i64>)>, ParseError> {
+    let mut parsed = vec![];
+
+    for line in input.lines() {
+        let (result, values) = line.split_once(':').ok_or_else(|| {
+            ParseError::unexpected_end_of(input, line, "':' after the test value")
+        })?;
+        let result = non_negative(input, result)?;
+        let values = values
+            .split_whitespace()
+            .map(|token| non_negative(input, token))
+            .collect::<Result<Vec<i64>, _>>()?;
+
+        if values.is_empty() {
+            return Err(ParseError::unexpected_end_of(
+                input,
+                line,
+                "a number after ':'",
+            ));
+        }
+        parsed.push((result, values));
+    }
+    Ok(parsed)
+}
+
+fn non_negative(input: &str, token: &str) -> Result<i64, ParseError> {
+    match parse::number::<i64>(input, token)? {
+        value if value >= 0 => Ok(value),
+        _ => Err(ParseError::unexpected(
+            input,
+            token,
+            "a non-negative number",
+        )),
+    }
+}
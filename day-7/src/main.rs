@@ -0,0 +1,5 @@
+use aoc_common::OrExit;
+
+fn main() {
+    aoc_common::run_day::<day_7::Day7>(["total sum: ", "total sum: "]).or_exit();
+}
@@ -0,0 +1,47 @@
+//! Slow but obviously correct solutions, checked against the fast ones by `aoc check`. They
+//! evaluate every combination of operators from left to right.
+
+use aoc_common::Answer;
+
+use crate::parse_input;
+
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    calibration_result(input, 2)
+}
+
+pub fn part2(input: &str) -> aoc_common::Result<Answer> {
+    calibration_result(input, 3)
+}
+
+/// `operators` is 2 for adding and multiplying, 3 to also concatenate.
+fn calibration_result(input: &str, operators: u64) -> aoc_common::Result<Answer> {
+    let equations = parse_input(input)?;
+
+    let mut total = 0i128;
+    for (test_value, values) in equations {
+        let gaps = values.len() as u32 - 1;
+        let solvable = (0..operators.pow(gaps)).any(|combination| {
+            evaluate(&values, combination, operators) == Some(i128::from(test_value))
+        });
+        if solvable {
+            total += i128::from(test_value);
+        }
+    }
+    Ok(total.into())
+}
+
+/// Reads the operators from the digits of `combination` in base `operators`. `None` when the
+/// result does not fit in an `i128`.
+fn evaluate(values: &[i64], mut combination: u64, operators: u64) -> Option<i128> {
+    let mut result = i128::from(values[0]);
+    for &value in &values[1..] {
+        let value = i128::from(value);
+        result = match combination % operators {
+            0 => result.checked_add(value)?,
+            1 => result.checked_mul(value)?,
+            _ => format!("{}{}", result, value).parse().ok()?,
+        };
+        combination /= operators;
+    }
+    Some(result)
+}
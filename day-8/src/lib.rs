@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::iter;
+
+use aoc_common::{Answer, Grid, Override, Point, Solution};
+use itertools::Itertools;
+
+mod reference;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const INPUT_PATH: &'static str = "day-8/input.txt";
+
+    type Input = Grid<char>;
+
+    fn parse_with(input: &str, _overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Answer> {
+        let res = get_antennas_grouped(input)
+            .iter()
+            .flat_map(|(_key, value)| get_possible_antinode_coords(value.clone()))
+            .unique()
+            .filter(|&antinode_coord| input.get(antinode_coord).is_some())
+            .count();
+
+        Ok(res.into())
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Answer> {
+        let res = get_antennas_grouped(input)
+            .iter()
+            .flat_map(|(_key, value)| get_possible_antinode_coords_part2(value.clone(), input))
+            .unique()
+            .count();
+
+        Ok(res.into())
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part2(input))
+    }
+}
+
+fn get_possible_antinode_coords(antenna_coords: Vec<Point>) -> Vec<Point> {
+    let res = antenna_coords
+        .iter()
+        .combinations(2)
+        .flat_map(|pair| {
+            let (delta_x, delta_y) = pair[0].signed_offset_to(*pair[1]);
+            let first = pair[0].checked_add_offset((-delta_x, -delta_y));
+            let second = pair[1].checked_add_offset((delta_x, delta_y));
+            vec![first, second]
+        })
+        .flatten()
+        .collect::<Vec<_>>();
+    res
+}
+
+/// Every position of `map` in line with two of the antennas.
+fn get_possible_antinode_coords_part2(antenna_coords: Vec<Point>, map: &Grid<char>) -> Vec<Point> {
+    let res = antenna_coords
+        .iter()
+        .combinations(2)
+        .flat_map(|pair| {
+            // The smallest step along the line, taken both ways from one of the antennas so that
+            // the positions between them count too, until the line leaves the map.
+            let (delta_x, delta_y) = pair[0].signed_offset_to(*pair[1]);
+            let divisor = gcd(delta_x.unsigned_abs(), delta_y.unsigned_abs()).max(1) as i64;
+            let (delta_x, delta_y) = (delta_x / divisor, delta_y / divisor);
+            let walk = move |step: (i64, i64)| {
+                iter::successors(Some(*pair[0]), move |position| {
+                    position
+                        .checked_add_offset(step)
+                        .filter(|&next| map.get(next).is_some())
+                })
+            };
+
+            walk((delta_x, delta_y))
+                .chain(walk((-delta_x, -delta_y)))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    res
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+fn get_antennas_grouped(map: &Grid<char>) -> HashMap<char, Vec<Point>> {
+    map.iter()
+        .filter(|(_coords, value)| value.ne(&&'.'))
+        .into_group_map_by(|(_coords, value)| **value)
+        .into_iter()
+        .map(|(key, value)| {
+            let coords = value.iter().map(|&(coords, _c)| coords).collect();
+            (key, coords)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::testing::{answer, reference};
+
+    use super::*;
+
+    #[test]
+    fn antinodes_between_antennas_count() {
+        // The antennas are (2, 4) apart, so (1, 2) between them and (3, 6) beyond them are in
+        // line with both.
+        let map = "a...\n....\n....\n....\n..a.\n....\n....\n....\n....\n";
+
+        assert_eq!(answer(Day8::solve_part2, map), "4");
+        assert_eq!(reference(Day8::reference_part2, map), "4");
+    }
+
+    #[test]
+    fn antinodes_reach_the_edge_of_the_map() {
+        // Every position of the row is in line with the two antennas 4 apart.
+        let map = format!("a...a{}\n", ".".repeat(295));
+
+        assert_eq!(answer(Day8::solve_part2, &map), "300");
+        assert_eq!(reference(Day8::reference_part2, &map), "300");
+    }
+}
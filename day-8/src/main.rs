@@ -0,0 +1,5 @@
+use aoc_common::OrExit;
+
+fn main() {
+    aoc_common::run_day::<day_8::Day8>(["antinode count: ", "antinode count: "]).or_exit();
+}
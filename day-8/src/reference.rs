@@ -0,0 +1,54 @@
+//! Slow but obviously correct solutions, checked against the fast ones by `aoc check`. They test
+//! every position of the map against every pair of antennas of the same frequency.
+
+use aoc_common::{Answer, Grid, Point, Solution};
+
+use crate::Day8;
+
+/// Positions in line with two antennas and twice as far from one of them as from the other.
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    let map = Day8::parse(input)?;
+
+    let count = antinodes(&map, |(x, y), first, second| {
+        let offset = |(ax, ay): (i64, i64)| (ax - x, ay - y);
+        let (to_first, to_second) = (offset(first), offset(second));
+        to_first == (2 * to_second.0, 2 * to_second.1)
+            || to_second == (2 * to_first.0, 2 * to_first.1)
+    });
+    Ok(count.into())
+}
+
+/// Positions in line with two antennas, at any distance.
+pub fn part2(input: &str) -> aoc_common::Result<Answer> {
+    let map = Day8::parse(input)?;
+
+    let count = antinodes(&map, |(x, y), (first_x, first_y), (second_x, second_y)| {
+        (first_x - x) * (second_y - y) == (second_x - x) * (first_y - y)
+    });
+    Ok(count.into())
+}
+
+/// The positions that `is_antinode` holds for with some pair of antennas of one frequency.
+fn antinodes(
+    map: &Grid<char>,
+    is_antinode: impl Fn((i64, i64), (i64, i64), (i64, i64)) -> bool,
+) -> usize {
+    let antennas = map
+        .iter()
+        .filter(|(_, frequency)| **frequency != '.')
+        .map(|(Point { x, y }, &frequency)| ((x as i64, y as i64), frequency))
+        .collect::<Vec<_>>();
+
+    map.positions()
+        .filter(|&Point { x, y }| {
+            antennas
+                .iter()
+                .enumerate()
+                .any(|(index, &(first, frequency))| {
+                    antennas[index + 1..].iter().any(|&(second, other)| {
+                        frequency == other && is_antinode((x as i64, y as i64), first, second)
+                    })
+                })
+        })
+        .count()
+}
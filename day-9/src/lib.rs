@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use aoc_common::{Answer, Override, ParseError, Solution, debug, trace};
+use itertools::Itertools;
+
+mod reference;
+
+pub struct Day9;
+
+/// The file ID stored at each disk index, -1 for free space.
+type DiskMap = HashMap<usize, i64>;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const INPUT_PATH: &'static str = "day-9/input.txt";
+
+    type Input = DiskMap;
+
+    fn parse_with(input: &str, _overrides: &[Override]) -> aoc_common::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> aoc_common::Result<Answer> {
+        let mut after_rearrage = input.clone();
+
+        let (mut filled_part, mut empty_part): (Vec<_>, Vec<_>) =
+            input.iter().partition(|item| *item.1 >= 0);
+        empty_part.sort_by(|pair1, pair2| pair1.0.cmp(pair2.0));
+        filled_part.sort_by(|pair1, pair2| pair2.0.cmp(pair1.0));
+
+        empty_part
+            .iter()
+            .zip(filled_part.iter())
+            .filter(|(empty_part_pair, filled_part_pair)| empty_part_pair.0 < filled_part_pair.0)
+            .for_each(|(empty_part_pair, filled_part_pair)| {
+                after_rearrage.insert(*empty_part_pair.0, *filled_part_pair.1);
+                after_rearrage.insert(*filled_part_pair.0, -1);
+            });
+
+        Ok(checksum(&after_rearrage).into())
+    }
+
+    fn part2(input: &Self::Input) -> aoc_common::Result<Answer> {
+        let (filled_part, empty_part): (Vec<_>, Vec<_>) =
+            input.clone().into_iter().partition(|item| item.1 >= 0);
+
+        let mut after_rearrage = input.clone();
+        let mut empty_blocks = get_empty_disk_blocks(empty_part);
+        let filled_blocks = get_disk_blocks(filled_part);
+
+        trace!("input {:?}", input);
+        debug!("filled_blocks {:?}", filled_blocks);
+
+        for filled_block in filled_blocks {
+            trace!("empty_blocks {:?}", empty_blocks);
+            for empty_block in empty_blocks.iter_mut() {
+                if empty_block.0 > filled_block.1 {
+                    continue;
+                }
+
+                let filled_block_size = filled_block.2 + 1 - filled_block.1;
+                let empty_block_size = empty_block.1 + 1 - empty_block.0;
+
+                if empty_block_size >= filled_block_size {
+                    for ind_to_switch in 0..filled_block_size {
+                        after_rearrage.insert(filled_block.1 + ind_to_switch, -1);
+                        after_rearrage.insert(empty_block.0 + ind_to_switch, filled_block.0);
+                    }
+                    *empty_block = (empty_block.0 + filled_block_size, empty_block.1);
+                    break;
+                }
+            }
+        }
+
+        Ok(checksum(&after_rearrage).into())
+    }
+
+    fn reference_part1(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part1(input))
+    }
+
+    fn reference_part2(input: &str) -> Option<aoc_common::Result<Answer>> {
+        Some(reference::part2(input))
+    }
+}
+
+fn checksum(disk_map: &DiskMap) -> i64 {
+    disk_map
+        .iter()
+        .sorted_by_key(|pair| pair.0)
+        .filter(|pair| *pair.1 >= 0)
+        .fold(0i64, |agg, pair| {
+            agg + ((*pair.1) * i64::try_from(*pair.0).unwrap_or_default())
+        })
+}
+
+fn get_empty_disk_blocks(empty_parts: Vec<(usize, i64)>) -> Vec<(usize, usize)> {
+    let mut res: Vec<(usize, usize)> = vec![];
+    let mut current_block: Vec<(usize, i64)> = vec![];
+
+    empty_parts
+        .iter()
+        .sorted_by_key(|pair| pair.0)
+        .for_each(|pair| {
+            trace!("pair {:?}, last {:?}", *pair, current_block.last());
+            match current_block.last() {
+                Some(last) => {
+                    if last.0.abs_diff(pair.0) == 1 {
+                        current_block.push(*pair);
+                    } else {
+                        let add_to_res_opt =
+                            current_block
+                                .iter()
+                                .min_by_key(|p| p.0)
+                                .and_then(|&min_ind| {
+                                    current_block
+                                        .iter()
+                                        .copied()
+                                        .max_by_key(|p| p.0)
+                                        .map(|max_ind| (min_ind.0, max_ind.0))
+                                });
+
+                        if let Some(add_to_res) = add_to_res_opt {
+                            res.push(add_to_res);
+                            current_block = vec![*pair];
+                        }
+                    }
+                }
+                None => {
+                    current_block.push(*pair);
+                }
+            };
+        });
+
+    let add_to_res_opt = current_block
+        .iter()
+        .min_by_key(|p| p.0)
+        .and_then(|&min_ind| {
+            current_block
+                .iter()
+                .copied()
+                .max_by_key(|p| p.0)
+                .map(|max_ind| (min_ind.0, max_ind.0))
+        });
+    if let Some(add_to_res) = add_to_res_opt {
+        res.push(add_to_res);
+    }
+
+    res.sort_by_key(|item| item.0);
+    res
+}
+
+fn get_disk_blocks(non_empty_parts: Vec<(usize, i64)>) -> Vec<(i64, usize, usize)> {
+    let res: Vec<(i64, usize, usize)> = non_empty_parts
+        .iter()
+        .into_group_map_by(|(_index, file_id)| *file_id)
+        .iter()
+        .flat_map(|(file_id, index_file_id_pairs)| {
+            index_file_id_pairs
+                .iter()
+                .max_by(|first, sec| first.0.cmp(&sec.0))
+                .and_then(|max_ind| {
+                    index_file_id_pairs
+                        .iter()
+                        .min_by(|first, sec| first.0.cmp(&sec.0))
+                        .map(|min_ind| {
+                            (
+                                *file_id, min_ind.0,
+                                max_ind.0,
+                                // index_file_id_pairs.iter().map(|&&x| x).collect(),
+                            )
+                        })
+                })
+        })
+        .sorted_by_key(|block| -block.0)
+        .collect::<Vec<_>>();
+    res
+}
+
+fn get_disk_map(input: Vec<(i64, i64)>) -> DiskMap {
+    let mut disk_index: usize = 0;
+    let mut ret: HashMap<usize, i64> = HashMap::new();
+
+    for (file_id, (file_size, empty_space)) in input.iter().enumerate() {
+        for _index_in_file in 0..*file_size {
+            ret.insert(disk_index, file_id as i64);
+            disk_index += 1;
+        }
+
+        for _index_in_empty in 0..*empty_space {
+            ret.insert(disk_index, -1);
+            disk_index += 1;
+        }
+    }
+
+    ret
+}
+
+fn parse_input(input: &str) -> Result<DiskMap, ParseError> {
+    let digits = input
+        .trim_end()
+        .char_indices()
+        .map(|(offset, c)| {
+            c.to_digit(10).map(i64::from).ok_or_else(|| {
+                ParseError::unexpected(input, &input[offset..offset + c.len_utf8()], "a digit")
+            })
+        })
+        .collect::<Result<Vec<i64>, _>>()?;
+
+    let pairs = digits
+        .chunks(2)
+        .map(|pair| (pair[0], pair.get(1).copied().unwrap_or(0)))
+        .collect();
+
+    Ok(get_disk_map(pairs))
+}
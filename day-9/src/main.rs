@@ -0,0 +1,5 @@
+use aoc_common::OrExit;
+
+fn main() {
+    aoc_common::run_day::<day_9::Day9>(["checksum: ", "checksum: "]).or_exit();
+}
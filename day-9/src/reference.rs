@@ -0,0 +1,62 @@
+//! Slow but obviously correct solutions, checked against the fast ones by `aoc check`. They keep
+//! the disk as a list of blocks and move one block, or one file, at a time.
+
+use aoc_common::{Answer, ParseError};
+
+pub fn part1(input: &str) -> aoc_common::Result<Answer> {
+    let mut disk = blocks(input)?;
+
+    // Moves the last file block to the first free block for as long as that one is further left.
+    loop {
+        let first_free = disk.iter().position(Option::is_none);
+        let last_file = disk.iter().rposition(Option::is_some);
+        match (first_free, last_file) {
+            (Some(free), Some(file)) if free < file => disk.swap(free, file),
+            _ => break,
+        }
+    }
+    Ok(checksum(&disk).into())
+}
+
+/// Tries to move every file once, from the highest ID down.
+pub fn part2(input: &str) -> aoc_common::Result<Answer> {
+    let mut disk = blocks(input)?;
+    let highest = disk.iter().flatten().copied().max().unwrap_or(0);
+
+    for id in (0..=highest).rev() {
+        let Some(start) = disk.iter().position(|&block| block == Some(id)) else {
+            continue;
+        };
+        let len = disk.iter().filter(|&&block| block == Some(id)).count();
+
+        // The leftmost span of enough free blocks that starts before the file.
+        let free = (0..start).find(|&free| disk[free..free + len].iter().all(Option::is_none));
+        if let Some(free) = free {
+            for offset in 0..len {
+                disk.swap(free + offset, start + offset);
+            }
+        }
+    }
+    Ok(checksum(&disk).into())
+}
+
+/// The file ID on every block, `None` on free ones.
+fn blocks(input: &str) -> Result<Vec<Option<u64>>, ParseError> {
+    let mut disk = vec![];
+    for (index, c) in input.trim_end().char_indices() {
+        let digit = &input[index..index + c.len_utf8()];
+        let len = c
+            .to_digit(10)
+            .ok_or_else(|| ParseError::unexpected(input, digit, "a digit"))?;
+        let block = (index % 2 == 0).then_some(index as u64 / 2);
+        disk.extend(std::iter::repeat_n(block, len as usize));
+    }
+    Ok(disk)
+}
+
+fn checksum(disk: &[Option<u64>]) -> u64 {
+    disk.iter()
+        .enumerate()
+        .map(|(position, block)| position as u64 * block.unwrap_or(0))
+        .sum()
+}